@@ -8,6 +8,22 @@ pub struct PyMutex {
     pub(crate) _pin: PhantomPinned,
 }
 
+impl PyMutex {
+    /// Creates a new unlocked mutex.
+    pub const fn new() -> PyMutex {
+        PyMutex {
+            _bits: AtomicU8::new(0),
+            _pin: PhantomPinned,
+        }
+    }
+}
+
+impl Default for PyMutex {
+    fn default() -> Self {
+        PyMutex::new()
+    }
+}
+
 extern "C" {
     pub fn PyMutex_Lock(m: *mut PyMutex);
     pub fn PyMutex_Unlock(m: *mut PyMutex);
@@ -11,6 +11,7 @@ use syn::{
 };
 
 pub mod kw {
+    syn::custom_keyword!(alias);
     syn::custom_keyword!(annotation);
     syn::custom_keyword!(attribute);
     syn::custom_keyword!(cancel_handle);
@@ -23,12 +24,16 @@ pub mod kw {
     syn::custom_keyword!(from_py_with);
     syn::custom_keyword!(frozen);
     syn::custom_keyword!(get);
+    syn::custom_keyword!(gc);
     syn::custom_keyword!(get_all);
     syn::custom_keyword!(hash);
+    syn::custom_keyword!(int_enum);
     syn::custom_keyword!(into_py_with);
     syn::custom_keyword!(item);
+    syn::custom_keyword!(leak_on_foreign_drop);
     syn::custom_keyword!(from_item_all);
     syn::custom_keyword!(mapping);
+    syn::custom_keyword!(match_args);
     syn::custom_keyword!(module);
     syn::custom_keyword!(name);
     syn::custom_keyword!(ord);
@@ -43,6 +48,7 @@ pub mod kw {
     syn::custom_keyword!(submodule);
     syn::custom_keyword!(text_signature);
     syn::custom_keyword!(transparent);
+    syn::custom_keyword!(traverse);
     syn::custom_keyword!(unsendable);
     syn::custom_keyword!(weakref);
     syn::custom_keyword!(gil_used);
@@ -231,6 +237,19 @@ pub enum RenamingRule {
     Uppercase,
 }
 
+impl RenamingRule {
+    pub const ALL: [RenamingRule; 8] = [
+        RenamingRule::CamelCase,
+        RenamingRule::KebabCase,
+        RenamingRule::Lowercase,
+        RenamingRule::PascalCase,
+        RenamingRule::ScreamingKebabCase,
+        RenamingRule::ScreamingSnakeCase,
+        RenamingRule::SnakeCase,
+        RenamingRule::Uppercase,
+    ];
+}
+
 /// A helper type which parses a renaming rule via a literal string
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RenamingRuleLitStr {
@@ -302,9 +321,35 @@ impl ToTokens for TextSignatureAttributeValue {
     }
 }
 
+/// For specifying the fields listed in `__match_args__`, e.g. `match_args(x, y)`.
+#[derive(Clone, Debug)]
+pub struct MatchArgsAttribute {
+    pub kw: kw::match_args,
+    pub fields: Punctuated<Ident, Comma>,
+}
+
+impl Parse for MatchArgsAttribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let kw = input.parse()?;
+        let content;
+        syn::parenthesized!(content in input);
+        let fields = content.parse_terminated(Ident::parse_any, Token![,])?;
+        Ok(MatchArgsAttribute { kw, fields })
+    }
+}
+
+impl ToTokens for MatchArgsAttribute {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.kw.to_tokens(tokens);
+        let fields = &self.fields;
+        tokens.extend(quote! { (#fields) });
+    }
+}
+
 pub type ExtendsAttribute = KeywordAttribute<kw::extends, Path>;
 pub type FreelistAttribute = KeywordAttribute<kw::freelist, Box<Expr>>;
 pub type ModuleAttribute = KeywordAttribute<kw::module, LitStr>;
+pub type AliasAttribute = KeywordAttribute<kw::alias, NameLitStr>;
 pub type NameAttribute = KeywordAttribute<kw::name, NameLitStr>;
 pub type RenameAllAttribute = KeywordAttribute<kw::rename_all, RenamingRuleLitStr>;
 pub type StrFormatterAttribute = OptionalKeywordAttribute<kw::str, StringFormatter>;
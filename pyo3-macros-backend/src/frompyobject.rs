@@ -2,6 +2,7 @@ use crate::attributes::{
     self, get_pyo3_options, CrateAttribute, DefaultAttribute, FromPyWithAttribute,
     RenameAllAttribute, RenamingRule,
 };
+use crate::pyimpl::get_cfg_attributes;
 use crate::utils::{self, deprecated_from_py_with, Ctx};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned, ToTokens};
@@ -36,6 +37,9 @@ impl<'a> Enum<'a> {
             .iter()
             .map(|variant| {
                 let mut variant_options = ContainerOptions::from_attrs(&variant.attrs)?;
+                if let Some(int_enum) = variant_options.int_enum {
+                    bail_spanned!(int_enum.span() => "`int_enum` is only supported at top level for enums");
+                }
                 if let Some(rename_all) = &options.rename_all {
                     ensure_spanned!(
                         variant_options.rename_all.is_none(),
@@ -470,6 +474,8 @@ struct ContainerOptions {
     krate: Option<CrateAttribute>,
     /// Converts the field idents according to the [RenamingRule] before extraction
     rename_all: Option<RenameAllAttribute>,
+    /// Extract a field-less enum from its discriminant as a Python `int`.
+    int_enum: Option<attributes::kw::int_enum>,
 }
 
 /// Attributes for deriving FromPyObject scoped on containers.
@@ -484,6 +490,8 @@ enum ContainerPyO3Attribute {
     Crate(CrateAttribute),
     /// Converts the field idents according to the [RenamingRule] before extraction
     RenameAll(RenameAllAttribute),
+    /// Extract a field-less enum from its discriminant as a Python `int`.
+    IntEnum(attributes::kw::int_enum),
 }
 
 impl Parse for ContainerPyO3Attribute {
@@ -503,6 +511,8 @@ impl Parse for ContainerPyO3Attribute {
             input.parse().map(ContainerPyO3Attribute::Crate)
         } else if lookahead.peek(attributes::kw::rename_all) {
             input.parse().map(ContainerPyO3Attribute::RenameAll)
+        } else if lookahead.peek(attributes::kw::int_enum) {
+            input.parse().map(ContainerPyO3Attribute::IntEnum)
         } else {
             Err(lookahead.error())
         }
@@ -552,6 +562,13 @@ impl ContainerOptions {
                             );
                             options.rename_all = Some(rename_all);
                         }
+                        ContainerPyO3Attribute::IntEnum(kw) => {
+                            ensure_spanned!(
+                                options.int_enum.is_none(),
+                                kw.span() => "`int_enum` may only be provided once"
+                            );
+                            options.int_enum = Some(kw);
+                        }
                     }
                 }
             }
@@ -684,6 +701,48 @@ fn verify_and_get_lifetime(generics: &syn::Generics) -> Result<Option<&syn::Life
     Ok(lifetime)
 }
 
+/// Build derivation body for `#[pyo3(int_enum)]` enums, which are extracted from their discriminant.
+fn build_int_enum(
+    data_enum: &DataEnum,
+    ident: &Ident,
+    attrs: &[Attribute],
+    ctx: &Ctx,
+) -> Result<TokenStream> {
+    let Ctx { pyo3_path, .. } = ctx;
+    ensure_spanned!(
+        !data_enum.variants.is_empty(),
+        ident.span() => "cannot derive FromPyObject for empty enum"
+    );
+    let repr_type = utils::enum_repr_type(attrs)?;
+    let variants = data_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            ensure_spanned!(
+                matches!(variant.fields, Fields::Unit),
+                variant.span() => "`int_enum` requires all variants to be field-less"
+            );
+            let var_ident = &variant.ident;
+            let cfg_attrs = get_cfg_attributes(&variant.attrs);
+            Ok(quote! {
+                #(#cfg_attrs)*
+                if value == #ident::#var_ident as #repr_type {
+                    return ::std::result::Result::Ok(#ident::#var_ident);
+                }
+            })
+        })
+        .collect::<Result<TokenStream>>()?;
+    let ty_name = ident.to_string();
+
+    Ok(quote! {
+        let value: #repr_type = #pyo3_path::types::PyAnyMethods::extract(obj)?;
+        #variants
+        ::std::result::Result::Err(#pyo3_path::exceptions::PyValueError::new_err(
+            ::std::format!("{} is not a valid {}", value, #ty_name)
+        ))
+    })
+}
+
 /// Derive FromPyObject for enums and structs.
 ///
 ///   * Max 1 lifetime specifier, will be tied to `FromPyObject`'s specifier
@@ -721,13 +780,20 @@ pub fn build_derive_from_pyobject(tokens: &DeriveInput) -> Result<TokenStream> {
                 bail_spanned!(tokens.span() => "`transparent` or `annotation` is not supported \
                                                 at top level for enums");
             }
-            let en = Enum::new(en, &tokens.ident, options)?;
-            en.build(ctx)
+            if options.int_enum.is_some() {
+                build_int_enum(en, &tokens.ident, &tokens.attrs, ctx)?
+            } else {
+                let en = Enum::new(en, &tokens.ident, options)?;
+                en.build(ctx)
+            }
         }
         syn::Data::Struct(st) => {
             if let Some(lit_str) = &options.annotation {
                 bail_spanned!(lit_str.span() => "`annotation` is unsupported for structs");
             }
+            if let Some(int_enum) = options.int_enum {
+                bail_spanned!(int_enum.span() => "`int_enum` is only supported for enums");
+            }
             let ident = &tokens.ident;
             let st = Container::new(&st.fields, parse_quote!(#ident), options)?;
             st.build(ctx)
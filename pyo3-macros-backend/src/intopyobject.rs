@@ -1,5 +1,6 @@
 use crate::attributes::{self, get_pyo3_options, CrateAttribute, IntoPyWithAttribute};
-use crate::utils::Ctx;
+use crate::pyimpl::get_cfg_attributes;
+use crate::utils::{enum_repr_type, Ctx};
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
 use syn::ext::IdentExt;
@@ -16,6 +17,8 @@ enum ContainerPyO3Attribute {
     Transparent(attributes::kw::transparent),
     /// Change the path for the pyo3 crate
     Crate(CrateAttribute),
+    /// Convert a field-less enum to its discriminant as a Python `int`.
+    IntEnum(attributes::kw::int_enum),
 }
 
 impl Parse for ContainerPyO3Attribute {
@@ -26,6 +29,8 @@ impl Parse for ContainerPyO3Attribute {
             Ok(ContainerPyO3Attribute::Transparent(kw))
         } else if lookahead.peek(Token![crate]) {
             input.parse().map(ContainerPyO3Attribute::Crate)
+        } else if lookahead.peek(attributes::kw::int_enum) {
+            input.parse().map(ContainerPyO3Attribute::IntEnum)
         } else {
             Err(lookahead.error())
         }
@@ -38,6 +43,8 @@ struct ContainerOptions {
     transparent: Option<attributes::kw::transparent>,
     /// Change the path for the pyo3 crate
     krate: Option<CrateAttribute>,
+    /// Convert a field-less enum to its discriminant as a Python `int`.
+    int_enum: Option<attributes::kw::int_enum>,
 }
 
 impl ContainerOptions {
@@ -70,6 +77,7 @@ impl ContainerOptions {
         match option {
             ContainerPyO3Attribute::Transparent(transparent) => set_option!(transparent),
             ContainerPyO3Attribute::Crate(krate) => set_option!(krate),
+            ContainerPyO3Attribute::IntEnum(int_enum) => set_option!(int_enum),
         }
         Ok(())
     }
@@ -522,6 +530,10 @@ impl<'a, const REF: bool> Enum<'a, REF> {
                 let attrs = ContainerOptions::from_attrs(&variant.attrs)?;
                 let var_ident = &variant.ident;
 
+                if let Some(int_enum) = attrs.int_enum {
+                    bail_spanned!(int_enum.span() => "`int_enum` is only supported at top level for enums");
+                }
+
                 ensure_spanned!(
                     !variant.fields.is_empty(),
                     variant.ident.span() => "cannot derive `IntoPyObject` for empty variants"
@@ -575,6 +587,49 @@ impl<'a, const REF: bool> Enum<'a, REF> {
     }
 }
 
+/// Build derivation body for `#[pyo3(int_enum)]` enums, which convert to their discriminant.
+fn build_int_enum(
+    data_enum: &DataEnum,
+    ident: &Ident,
+    attrs: &[Attribute],
+    ctx: &Ctx,
+) -> Result<IntoPyObjectImpl> {
+    let Ctx { pyo3_path, .. } = ctx;
+    ensure_spanned!(
+        !data_enum.variants.is_empty(),
+        ident.span() => "cannot derive `IntoPyObject` for empty enum"
+    );
+    let repr_type = enum_repr_type(attrs)?;
+    let variants = data_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            ensure_spanned!(
+                matches!(variant.fields, Fields::Unit),
+                variant.span() => "`int_enum` requires all variants to be field-less"
+            );
+            let var_ident = &variant.ident;
+            let cfg_attrs = get_cfg_attributes(&variant.attrs);
+            Ok(quote! { #(#cfg_attrs)* #ident::#var_ident => #ident::#var_ident as #repr_type, })
+        })
+        .collect::<Result<TokenStream>>()?;
+
+    Ok(IntoPyObjectImpl {
+        types: IntoPyObjectTypes::Opaque {
+            target: quote!(#pyo3_path::types::PyInt),
+            output: quote!(#pyo3_path::Bound<'py, #pyo3_path::types::PyInt>),
+            error: quote!(::std::convert::Infallible),
+        },
+        body: quote! {
+            // Matching on each variant allows converting `&Self` without requiring `Copy`.
+            let value: #repr_type = match self {
+                #variants
+            };
+            #pyo3_path::conversion::IntoPyObject::into_pyobject(value, py)
+        },
+    })
+}
+
 // if there is a `'py` lifetime, we treat it as the `Python<'py>` lifetime
 fn verify_and_get_lifetime(generics: &syn::Generics) -> Option<&syn::LifetimeParam> {
     let mut lifetimes = generics.lifetimes();
@@ -614,10 +669,17 @@ pub fn build_derive_into_pyobject<const REF: bool>(tokens: &DeriveInput) -> Resu
             if options.transparent.is_some() {
                 bail_spanned!(tokens.span() => "`transparent` is not supported at top level for enums");
             }
-            let en = Enum::<REF>::new(en, &tokens.ident)?;
-            en.build(ctx)
+            if options.int_enum.is_some() {
+                build_int_enum(en, &tokens.ident, &tokens.attrs, ctx)?
+            } else {
+                let en = Enum::<REF>::new(en, &tokens.ident)?;
+                en.build(ctx)
+            }
         }
         syn::Data::Struct(st) => {
+            if let Some(int_enum) = options.int_enum {
+                bail_spanned!(int_enum.span() => "`int_enum` is only supported for enums");
+            }
             let ident = &tokens.ident;
             let st = Container::<REF>::new(
                 Some(Ident::new("self", Span::call_site())),
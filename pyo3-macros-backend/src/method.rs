@@ -32,6 +32,7 @@ pub struct RegularArg<'a> {
 pub struct VarargsArg<'a> {
     pub name: Cow<'a, syn::Ident>,
     pub ty: &'a syn::Type,
+    pub from_py_with: Option<FromPyWithAttribute>,
 }
 
 /// Pythons **kwarg argument
@@ -39,6 +40,7 @@ pub struct VarargsArg<'a> {
 pub struct KwargsArg<'a> {
     pub name: Cow<'a, syn::Ident>,
     pub ty: &'a syn::Type,
+    pub from_py_with: Option<FromPyWithAttribute>,
 }
 
 #[derive(Clone, Debug)]
@@ -85,10 +87,11 @@ impl<'a> FnArg<'a> {
 
     #[allow(clippy::wrong_self_convention)]
     pub fn from_py_with(&self) -> Option<&FromPyWithAttribute> {
-        if let FnArg::Regular(RegularArg { from_py_with, .. }) = self {
-            from_py_with.as_ref()
-        } else {
-            None
+        match self {
+            FnArg::Regular(RegularArg { from_py_with, .. })
+            | FnArg::VarArgs(VarargsArg { from_py_with, .. })
+            | FnArg::KwArgs(KwargsArg { from_py_with, .. }) => from_py_with.as_ref(),
+            FnArg::Py(..) | FnArg::CancelHandle(..) => None,
         }
     }
 
@@ -97,12 +100,14 @@ impl<'a> FnArg<'a> {
             name,
             ty,
             option_wrapped_type: None,
+            from_py_with,
             ..
         }) = self
         {
             *self = Self::VarArgs(VarargsArg {
                 name: name.clone(),
                 ty,
+                from_py_with: from_py_with.take(),
             });
             Ok(self)
         } else {
@@ -115,12 +120,14 @@ impl<'a> FnArg<'a> {
             name,
             ty,
             option_wrapped_type: Some(..),
+            from_py_with,
             ..
         }) = self
         {
             *self = Self::KwArgs(KwargsArg {
                 name: name.clone(),
                 ty,
+                from_py_with: from_py_with.take(),
             });
             Ok(self)
         } else {
@@ -407,6 +414,9 @@ pub struct FnSpec<'a> {
     // Wrapped python name. This should not have any leading r#.
     // r# can be removed by syn::ext::IdentExt::unraw()
     pub python_name: syn::Ident,
+    // Whether `python_name` was derived from the Rust name, rather than given explicitly.
+    // Getters and setters with a derived name are renamed by `#[pyclass(rename_all = "...")]`.
+    pub python_name_derived: bool,
     pub signature: FunctionSignature<'a>,
     pub convention: CallingConvention,
     pub text_signature: Option<TextSignatureAttribute>,
@@ -452,8 +462,10 @@ impl<'a> FnSpec<'a> {
         } = options;
 
         let mut python_name = name.map(|name| name.value.0);
+        let mut python_name_derived = python_name.is_none();
 
-        let fn_type = Self::parse_fn_type(sig, meth_attrs, &mut python_name)?;
+        let fn_type =
+            Self::parse_fn_type(sig, meth_attrs, &mut python_name, &mut python_name_derived)?;
         ensure_signatures_on_valid_method(&fn_type, signature.as_ref(), text_signature.as_ref())?;
 
         let name = &sig.ident;
@@ -487,6 +499,7 @@ impl<'a> FnSpec<'a> {
             name,
             convention,
             python_name,
+            python_name_derived,
             signature,
             text_signature,
             asyncness: sig.asyncness,
@@ -504,6 +517,7 @@ impl<'a> FnSpec<'a> {
         sig: &syn::Signature,
         meth_attrs: &mut Vec<syn::Attribute>,
         python_name: &mut Option<syn::Ident>,
+        python_name_derived: &mut bool,
     ) -> Result<FnType> {
         let mut method_attributes = parse_method_attributes(meth_attrs)?;
 
@@ -565,6 +579,7 @@ impl<'a> FnSpec<'a> {
                         python_name.replace(name).is_none(),
                         python_name.span() => "`name` may only be specified once"
                     );
+                    *python_name_derived = false;
                 } else if python_name.is_none() {
                     // Strip off "get_" prefix if needed
                     *python_name = strip_fn_name("get_");
@@ -578,6 +593,7 @@ impl<'a> FnSpec<'a> {
                         python_name.replace(name).is_none(),
                         python_name.span() => "`name` may only be specified once"
                     );
+                    *python_name_derived = false;
                 } else if python_name.is_none() {
                     // Strip off "set_" prefix if needed
                     *python_name = strip_fn_name("set_");
@@ -773,7 +789,7 @@ impl<'a> FnSpec<'a> {
             }
             CallingConvention::Fastcall => {
                 let mut holders = Holders::new();
-                let (arg_convert, args) = impl_arg_params(self, cls, true, &mut holders, ctx);
+                let (arg_convert, args) = impl_arg_params(self, cls, true, &mut holders, ctx)?;
                 let call = rust_call(args, &mut holders);
                 let init_holders = holders.init_holders(ctx);
 
@@ -786,8 +802,8 @@ impl<'a> FnSpec<'a> {
                         _kwnames: *mut #pyo3_path::ffi::PyObject
                     ) -> #pyo3_path::PyResult<*mut #pyo3_path::ffi::PyObject> {
                         let function = #rust_name; // Shadow the function name to avoid #3017
-                        #arg_convert
                         #init_holders
+                        #arg_convert
                         let result = #call;
                         result
                     }
@@ -795,7 +811,7 @@ impl<'a> FnSpec<'a> {
             }
             CallingConvention::Varargs => {
                 let mut holders = Holders::new();
-                let (arg_convert, args) = impl_arg_params(self, cls, false, &mut holders, ctx);
+                let (arg_convert, args) = impl_arg_params(self, cls, false, &mut holders, ctx)?;
                 let call = rust_call(args, &mut holders);
                 let init_holders = holders.init_holders(ctx);
 
@@ -807,8 +823,8 @@ impl<'a> FnSpec<'a> {
                         _kwargs: *mut #pyo3_path::ffi::PyObject
                     ) -> #pyo3_path::PyResult<*mut #pyo3_path::ffi::PyObject> {
                         let function = #rust_name; // Shadow the function name to avoid #3017
-                        #arg_convert
                         #init_holders
+                        #arg_convert
                         let result = #call;
                         result
                     }
@@ -816,7 +832,7 @@ impl<'a> FnSpec<'a> {
             }
             CallingConvention::TpNew => {
                 let mut holders = Holders::new();
-                let (arg_convert, args) = impl_arg_params(self, cls, false, &mut holders, ctx);
+                let (arg_convert, args) = impl_arg_params(self, cls, false, &mut holders, ctx)?;
                 let self_arg = self
                     .tp
                     .self_arg(cls, ExtractErrorMode::Raise, &mut holders, ctx);
@@ -831,8 +847,8 @@ impl<'a> FnSpec<'a> {
                     ) -> #pyo3_path::PyResult<*mut #pyo3_path::ffi::PyObject> {
                         use #pyo3_path::impl_::callback::IntoPyCallbackOutput;
                         let function = #rust_name; // Shadow the function name to avoid #3017
-                        #arg_convert
                         #init_holders
+                        #arg_convert
                         let result = #call;
                         let initializer: #pyo3_path::PyClassInitializer::<#cls> = result.convert(py)?;
                         #pyo3_path::impl_::pymethods::tp_new_impl(py, initializer, _slf)
@@ -1,13 +1,15 @@
 use crate::utils::{deprecated_from_py_with, Ctx};
 use crate::{
-    attributes::FromPyWithAttribute,
+    attributes::{ErrorCombiner, FromPyWithAttribute},
     method::{FnArg, FnSpec, RegularArg},
     pyfunction::FunctionSignature,
     quotes::some_wrap,
 };
-use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote, quote_spanned};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
+use syn::visit_mut::{self, VisitMut};
+use syn::Result;
 
 pub struct Holders {
     holders: Vec<syn::Ident>,
@@ -50,7 +52,7 @@ pub fn impl_arg_params(
     fastcall: bool,
     holders: &mut Holders,
     ctx: &Ctx,
-) -> (TokenStream, Vec<TokenStream>) {
+) -> Result<(TokenStream, Vec<TokenStream>)> {
     let args_array = syn::Ident::new("output", Span::call_site());
     let Ctx { pyo3_path, .. } = ctx;
 
@@ -80,14 +82,14 @@ pub fn impl_arg_params(
             .enumerate()
             .map(|(i, arg)| impl_arg_param(arg, i, &mut 0, holders, ctx))
             .collect();
-        return (
+        return Ok((
             quote! {
                 let _args = unsafe { #pyo3_path::impl_::pymethods::BoundRef::ref_from_ptr(py, &_args) };
                 let _kwargs = #pyo3_path::impl_::pymethods::BoundRef::ref_from_ptr_or_opt(py, &_kwargs);
                 #from_py_with
             },
             arg_convert,
-        );
+        ));
     };
 
     let positional_parameter_names = &spec.signature.python_signature.positional_parameters;
@@ -112,14 +114,35 @@ pub fn impl_arg_params(
 
     let num_params = positional_parameter_names.len() + keyword_only_parameters.len();
 
+    // If a default value refers to an earlier argument, the arguments are bound to local
+    // variables in order, so that the default sees the extracted (or defaulted) value. The
+    // locals are prefixed so they cannot shadow the other locals of the generated wrapper.
+    let bind_arguments = defaults_reference_earlier_arguments(&spec.signature);
+    let mut bindings = TokenStream::new();
+    let mut earlier: Vec<&syn::Ident> = Vec::new();
     let mut option_pos = 0usize;
     let param_conversion = spec
         .signature
         .arguments
         .iter()
         .enumerate()
-        .map(|(i, arg)| impl_arg_param(arg, i, &mut option_pos, holders, ctx))
-        .collect();
+        .map(|(i, arg)| match arg {
+            FnArg::Regular(regular) if bind_arguments => {
+                let mut regular = regular.clone();
+                regular.default_value = regular
+                    .default_value
+                    .map(|default| rename_earlier_arguments(default, &earlier))
+                    .transpose()?;
+                let conversion =
+                    impl_arg_param(&FnArg::Regular(regular), i, &mut option_pos, holders, ctx);
+                let local = argument_local(arg.name());
+                bindings.extend(quote! { let #local = #conversion; });
+                earlier.push(arg.name());
+                Ok(quote! { #local })
+            }
+            _ => Ok(impl_arg_param(arg, i, &mut option_pos, holders, ctx)),
+        })
+        .collect::<Result<Vec<_>>>()?;
 
     let args_handler = if spec.signature.python_signature.varargs.is_some() {
         quote! { #pyo3_path::impl_::extract_argument::TupleVarargs }
@@ -161,7 +184,7 @@ pub fn impl_arg_params(
     };
 
     // create array of arguments, and then parse
-    (
+    Ok((
         quote! {
                 const DESCRIPTION: #pyo3_path::impl_::extract_argument::FunctionDescription = #pyo3_path::impl_::extract_argument::FunctionDescription {
                     cls_name: #cls_name,
@@ -174,9 +197,107 @@ pub fn impl_arg_params(
                 let mut #args_array = [::std::option::Option::None; #num_params];
                 let (_args, _kwargs) = #extract_expression;
                 #from_py_with
+                #bindings
         },
         param_conversion,
-    )
+    ))
+}
+
+/// Returns true if `tokens` contain the identifier `name`.
+fn mentions(tokens: TokenStream, name: &syn::Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == *name,
+        TokenTree::Group(group) => mentions(group.stream(), name),
+        _ => false,
+    })
+}
+
+/// Returns true if the default value of any argument mentions the name of an earlier argument.
+fn defaults_reference_earlier_arguments(signature: &FunctionSignature<'_>) -> bool {
+    let mut earlier: Vec<&syn::Ident> = Vec::new();
+    for arg in &signature.arguments {
+        if let FnArg::Regular(arg) = arg {
+            if let Some(default) = &arg.default_value {
+                let tokens = default.to_token_stream();
+                if earlier.iter().any(|name| mentions(tokens.clone(), name)) {
+                    return true;
+                }
+            }
+            earlier.push(&arg.name);
+        }
+    }
+    false
+}
+
+/// The local variable an argument is bound to when defaults reference earlier arguments.
+fn argument_local(name: &syn::Ident) -> syn::Ident {
+    format_ident!("__pyo3_arg_{}", name, span = name.span())
+}
+
+/// Replaces mentions of `earlier` arguments in a default value with their bound locals.
+///
+/// Only plain paths such as `start` are renamed, so fields, methods and struct literal members
+/// which happen to share an argument's name keep their meaning. Defaults which rebind an earlier
+/// argument's name, or mention it inside a macro invocation, are rejected.
+fn rename_earlier_arguments(mut default: syn::Expr, earlier: &[&syn::Ident]) -> Result<syn::Expr> {
+    struct Renamer<'a> {
+        earlier: &'a [&'a syn::Ident],
+        errors: ErrorCombiner,
+    }
+
+    impl Renamer<'_> {
+        fn is_earlier(&self, ident: &syn::Ident) -> bool {
+            self.earlier.contains(&ident)
+        }
+    }
+
+    impl VisitMut for Renamer<'_> {
+        fn visit_expr_path_mut(&mut self, expr: &mut syn::ExprPath) {
+            match expr.path.get_ident() {
+                Some(ident) if expr.qself.is_none() && self.is_earlier(ident) => {
+                    expr.path = argument_local(ident).into();
+                }
+                _ => visit_mut::visit_expr_path_mut(self, expr),
+            }
+        }
+
+        fn visit_field_value_mut(&mut self, field: &mut syn::FieldValue) {
+            // `Foo { start }` is shorthand for `Foo { start: start }`; only the value is renamed
+            if field.colon_token.is_none() {
+                field.colon_token = Some(Default::default());
+            }
+            self.visit_expr_mut(&mut field.expr);
+        }
+
+        fn visit_pat_ident_mut(&mut self, pat: &mut syn::PatIdent) {
+            if self.is_earlier(&pat.ident) {
+                self.errors.combine(err_spanned!(
+                    pat.ident.span() => format!("default values cannot rebind the argument `{}`", pat.ident)
+                ));
+            }
+            visit_mut::visit_pat_ident_mut(self, pat);
+        }
+
+        fn visit_macro_mut(&mut self, mac: &mut syn::Macro) {
+            if let Some(name) = self
+                .earlier
+                .iter()
+                .find(|name| mentions(mac.tokens.clone(), name))
+            {
+                self.errors.combine(err_spanned!(
+                    mac.span() => format!("default values cannot refer to the argument `{}` inside a macro invocation", name)
+                ));
+            }
+        }
+    }
+
+    let mut renamer = Renamer {
+        earlier,
+        errors: ErrorCombiner(None),
+    };
+    renamer.visit_expr_mut(&mut default);
+    renamer.errors.ensure_empty()?;
+    Ok(default)
 }
 
 fn impl_arg_param(
@@ -196,6 +317,17 @@ fn impl_arg_param(
             *option_pos += 1;
             impl_regular_arg_param(arg, from_py_with, arg_value, holders, ctx)
         }
+        FnArg::VarArgs(arg) if arg.from_py_with.is_some() => {
+            let from_py_with = format_ident!("from_py_with_{}", pos);
+            let name_str = arg.name.to_string();
+            quote_spanned! { arg.name.span() =>
+                #pyo3_path::impl_::extract_argument::from_py_with_varargs(
+                    &_args,
+                    #name_str,
+                    #from_py_with
+                )?
+            }
+        }
         FnArg::VarArgs(arg) => {
             let holder = holders.push_holder(arg.name.span());
             let name_str = arg.name.to_string();
@@ -207,6 +339,17 @@ fn impl_arg_param(
                 )?
             }
         }
+        FnArg::KwArgs(arg) if arg.from_py_with.is_some() => {
+            let from_py_with = format_ident!("from_py_with_{}", pos);
+            let name_str = arg.name.to_string();
+            quote_spanned! { arg.name.span() =>
+                #pyo3_path::impl_::extract_argument::from_py_with_varkeywords(
+                    _kwargs.as_deref(),
+                    #name_str,
+                    #from_py_with
+                )?
+            }
+        }
         FnArg::KwArgs(arg) => {
             let holder = holders.push_holder(arg.name.span());
             let name_str = arg.name.to_string();
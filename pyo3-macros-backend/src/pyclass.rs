@@ -11,20 +11,20 @@ use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, ImplItemFn, Result
 use crate::attributes::kw::frozen;
 use crate::attributes::{
     self, kw, take_pyo3_options, CrateAttribute, ErrorCombiner, ExtendsAttribute,
-    FreelistAttribute, ModuleAttribute, NameAttribute, NameLitStr, RenameAllAttribute,
-    StrFormatterAttribute,
+    FreelistAttribute, MatchArgsAttribute, ModuleAttribute, NameAttribute, NameLitStr,
+    RenameAllAttribute, StrFormatterAttribute,
 };
 use crate::konst::{ConstAttributes, ConstSpec};
 use crate::method::{FnArg, FnSpec, PyArg, RegularArg};
 use crate::pyfunction::ConstructorAttribute;
 use crate::pyimpl::{gen_py_const, get_cfg_attributes, PyClassMethodsType};
 use crate::pymethod::{
-    impl_py_class_attribute, impl_py_getter_def, impl_py_setter_def, MethodAndMethodDef,
-    MethodAndSlotDef, PropertyType, SlotDef, __GETITEM__, __HASH__, __INT__, __LEN__, __REPR__,
-    __RICHCMP__, __STR__,
+    impl_clear_slot, impl_py_class_attribute, impl_py_getter_def, impl_py_setter_def,
+    impl_traverse_slot, MethodAndMethodDef, MethodAndSlotDef, PropertyType, SlotDef, __GETITEM__,
+    __HASH__, __INT__, __LEN__, __REPR__, __RICHCMP__, __STR__,
 };
 use crate::pyversions::is_abi3_before;
-use crate::utils::{self, apply_renaming_rule, Ctx, LitCStr, PythonDoc};
+use crate::utils::{self, apply_renaming_rule, enum_repr_type, Ctx, LitCStr, PythonDoc};
 use crate::PyFunctionOptions;
 
 /// If the class is derived from a Rust `struct` or `enum`.
@@ -68,8 +68,11 @@ pub struct PyClassPyO3Options {
     pub get_all: Option<kw::get_all>,
     pub freelist: Option<FreelistAttribute>,
     pub frozen: Option<kw::frozen>,
+    pub gc: Option<kw::gc>,
     pub hash: Option<kw::hash>,
+    pub leak_on_foreign_drop: Option<kw::leak_on_foreign_drop>,
     pub mapping: Option<kw::mapping>,
+    pub match_args: Option<MatchArgsAttribute>,
     pub module: Option<ModuleAttribute>,
     pub name: Option<NameAttribute>,
     pub ord: Option<kw::ord>,
@@ -90,9 +93,12 @@ pub enum PyClassPyO3Option {
     Extends(ExtendsAttribute),
     Freelist(FreelistAttribute),
     Frozen(kw::frozen),
+    Gc(kw::gc),
     GetAll(kw::get_all),
     Hash(kw::hash),
+    LeakOnForeignDrop(kw::leak_on_foreign_drop),
     Mapping(kw::mapping),
+    MatchArgs(MatchArgsAttribute),
     Module(ModuleAttribute),
     Name(NameAttribute),
     Ord(kw::ord),
@@ -122,12 +128,18 @@ impl Parse for PyClassPyO3Option {
             input.parse().map(PyClassPyO3Option::Freelist)
         } else if lookahead.peek(attributes::kw::frozen) {
             input.parse().map(PyClassPyO3Option::Frozen)
+        } else if lookahead.peek(attributes::kw::gc) {
+            input.parse().map(PyClassPyO3Option::Gc)
         } else if lookahead.peek(attributes::kw::get_all) {
             input.parse().map(PyClassPyO3Option::GetAll)
         } else if lookahead.peek(attributes::kw::hash) {
             input.parse().map(PyClassPyO3Option::Hash)
+        } else if lookahead.peek(attributes::kw::leak_on_foreign_drop) {
+            input.parse().map(PyClassPyO3Option::LeakOnForeignDrop)
         } else if lookahead.peek(attributes::kw::mapping) {
             input.parse().map(PyClassPyO3Option::Mapping)
+        } else if lookahead.peek(attributes::kw::match_args) {
+            input.parse().map(PyClassPyO3Option::MatchArgs)
         } else if lookahead.peek(attributes::kw::module) {
             input.parse().map(PyClassPyO3Option::Module)
         } else if lookahead.peek(kw::name) {
@@ -200,9 +212,14 @@ impl PyClassPyO3Options {
             PyClassPyO3Option::Extends(extends) => set_option!(extends),
             PyClassPyO3Option::Freelist(freelist) => set_option!(freelist),
             PyClassPyO3Option::Frozen(frozen) => set_option!(frozen),
+            PyClassPyO3Option::Gc(gc) => set_option!(gc),
             PyClassPyO3Option::GetAll(get_all) => set_option!(get_all),
             PyClassPyO3Option::Hash(hash) => set_option!(hash),
+            PyClassPyO3Option::LeakOnForeignDrop(leak_on_foreign_drop) => {
+                set_option!(leak_on_foreign_drop)
+            }
             PyClassPyO3Option::Mapping(mapping) => set_option!(mapping),
+            PyClassPyO3Option::MatchArgs(match_args) => set_option!(match_args),
             PyClassPyO3Option::Module(module) => set_option!(module),
             PyClassPyO3Option::Name(name) => set_option!(name),
             PyClassPyO3Option::Ord(ord) => set_option!(ord),
@@ -332,12 +349,14 @@ struct FieldPyO3Options {
     get: Option<Annotated<kw::get, kw::get_all>>,
     set: Option<Annotated<kw::set, kw::set_all>>,
     name: Option<NameAttribute>,
+    traverse: Option<attributes::kw::traverse>,
 }
 
 enum FieldPyO3Option {
     Get(attributes::kw::get),
     Set(attributes::kw::set),
     Name(NameAttribute),
+    Traverse(attributes::kw::traverse),
 }
 
 impl Parse for FieldPyO3Option {
@@ -349,6 +368,8 @@ impl Parse for FieldPyO3Option {
             input.parse().map(FieldPyO3Option::Set)
         } else if lookahead.peek(attributes::kw::name) {
             input.parse().map(FieldPyO3Option::Name)
+        } else if lookahead.peek(attributes::kw::traverse) {
+            input.parse().map(FieldPyO3Option::Traverse)
         } else {
             Err(lookahead.error())
         }
@@ -361,6 +382,7 @@ impl FieldPyO3Options {
             get: None,
             set: None,
             name: None,
+            traverse: None,
         };
 
         for option in take_pyo3_options(attrs)? {
@@ -380,6 +402,11 @@ impl FieldPyO3Options {
                         return Err(syn::Error::new(options.name.span(), UNIQUE_NAME));
                     }
                 }
+                FieldPyO3Option::Traverse(kw) => {
+                    if options.traverse.replace(kw).is_some() {
+                        return Err(syn::Error::new(kw.span(), UNIQUE_TRAVERSE));
+                    }
+                }
             }
         }
 
@@ -425,26 +452,30 @@ fn impl_class(
     let (default_hash, default_hash_slot) =
         pyclass_hash(&args.options, &syn::parse_quote!(#cls), ctx)?;
 
+    let (default_gc, default_gc_slots) =
+        pyclass_gc(&args.options, &field_options, &syn::parse_quote!(#cls), ctx)?;
+
+    let (default_match_args, default_match_args_method) =
+        pyclass_match_args(&args.options, &field_options, &syn::parse_quote!(#cls), ctx)?;
+
     let mut slots = Vec::new();
     slots.extend(default_richcmp_slot);
     slots.extend(default_hash_slot);
     slots.extend(default_str_slot);
+    slots.extend(default_gc_slots);
 
-    let py_class_impl = PyClassImplsBuilder::new(
+    let mut default_methods = descriptors_to_items(
         cls,
-        args,
-        methods_type,
-        descriptors_to_items(
-            cls,
-            args.options.rename_all.as_ref(),
-            args.options.frozen,
-            field_options,
-            ctx,
-        )?,
-        slots,
-    )
-    .doc(doc)
-    .impl_all(ctx)?;
+        args.options.rename_all.as_ref(),
+        args.options.frozen,
+        field_options,
+        ctx,
+    )?;
+    default_methods.extend(default_match_args_method);
+
+    let py_class_impl = PyClassImplsBuilder::new(cls, args, methods_type, default_methods, slots)
+        .doc(doc)
+        .impl_all(ctx)?;
 
     Ok(quote! {
         impl #pyo3_path::types::DerefToPyAny for #cls {}
@@ -459,6 +490,8 @@ fn impl_class(
             #default_richcmp
             #default_hash
             #default_str
+            #default_gc
+            #default_match_args
         }
     })
 }
@@ -497,6 +530,10 @@ pub fn build_py_enum(
         bail_spanned!(extends.span() => "enums can't extend from other classes");
     } else if let Some(subclass) = &args.options.subclass {
         bail_spanned!(subclass.span() => "enums can't be inherited by other classes");
+    } else if let Some(gc) = &args.options.gc {
+        bail_spanned!(gc.span() => "`gc` is only supported for structs");
+    } else if let Some(match_args) = &args.options.match_args {
+        bail_spanned!(match_args.kw.span() => "`match_args` is only supported for structs");
     } else if enum_.variants.is_empty() {
         bail_spanned!(enum_.brace_token.span.join() => "#[pyclass] can't be used on enums without any variants");
     }
@@ -516,15 +553,6 @@ struct PyClassSimpleEnum<'a> {
 
 impl<'a> PyClassSimpleEnum<'a> {
     fn new(enum_: &'a mut syn::ItemEnum) -> syn::Result<Self> {
-        fn is_numeric_type(t: &syn::Ident) -> bool {
-            [
-                "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "usize",
-                "isize",
-            ]
-            .iter()
-            .any(|&s| t == s)
-        }
-
         fn extract_unit_variant_data(
             variant: &mut syn::Variant,
         ) -> syn::Result<PyClassEnumUnitVariant<'_>> {
@@ -544,21 +572,7 @@ impl<'a> PyClassSimpleEnum<'a> {
 
         let ident = &enum_.ident;
 
-        // According to the [reference](https://doc.rust-lang.org/reference/items/enumerations.html),
-        // "Under the default representation, the specified discriminant is interpreted as an isize
-        // value", so `isize` should be enough by default.
-        let mut repr_type = syn::Ident::new("isize", proc_macro2::Span::call_site());
-        if let Some(attr) = enum_.attrs.iter().find(|attr| attr.path().is_ident("repr")) {
-            let args =
-                attr.parse_args_with(Punctuated::<TokenStream, Token![!]>::parse_terminated)?;
-            if let Some(ident) = args
-                .into_iter()
-                .filter_map(|ts| syn::parse2::<syn::Ident>(ts).ok())
-                .find(is_numeric_type)
-            {
-                repr_type = ident;
-            }
-        }
+        let repr_type = enum_repr_type(&enum_.attrs)?;
 
         let variants: Vec<_> = enum_
             .variants
@@ -1666,6 +1680,7 @@ fn complex_enum_struct_variant_new<'a>(
         tp: crate::method::FnType::FnNew,
         name: &format_ident!("__pymethod_constructor__"),
         python_name: format_ident!("__new__"),
+        python_name_derived: false,
         signature,
         convention: crate::method::CallingConvention::TpNew,
         text_signature: None,
@@ -1720,6 +1735,7 @@ fn complex_enum_tuple_variant_new<'a>(
         tp: crate::method::FnType::FnNew,
         name: &format_ident!("__pymethod_constructor__"),
         python_name: format_ident!("__new__"),
+        python_name_derived: false,
         signature,
         convention: crate::method::CallingConvention::TpNew,
         text_signature: None,
@@ -1744,6 +1760,7 @@ fn complex_enum_variant_field_getter<'a>(
         tp: crate::method::FnType::Getter(self_type.clone()),
         name: field_name,
         python_name: field_name.clone(),
+        python_name_derived: false,
         signature,
         convention: crate::method::CallingConvention::Noargs,
         text_signature: None,
@@ -1775,6 +1792,7 @@ fn descriptors_to_items(
             name: Some(name),
             get: None,
             set: None,
+            ..
         } = options
         {
             return Err(syn::Error::new_spanned(name, USELESS_NAME));
@@ -2023,6 +2041,157 @@ fn pyclass_hash(
     }
 }
 
+/// Whether `ty` is spelled as `Py<...>` or `PyObject`.
+fn is_bare_py(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            path.segments.last().map_or(false, |segment| {
+                segment.ident == "Py" || segment.ident == "PyObject"
+            })
+        }
+        syn::Type::Group(group) => is_bare_py(&group.elem),
+        syn::Type::Paren(paren) => is_bare_py(&paren.elem),
+        _ => false,
+    }
+}
+
+fn pyclass_gc(
+    options: &PyClassPyO3Options,
+    field_options: &[(&syn::Field, FieldPyO3Options)],
+    cls: &syn::Type,
+    ctx: &Ctx,
+) -> Result<(Option<TokenStream>, Vec<MethodAndSlotDef>)> {
+    let Ctx { pyo3_path, .. } = ctx;
+    let gc = match options.gc {
+        Some(gc) => gc,
+        None => {
+            if let Some(traverse) = field_options
+                .iter()
+                .find_map(|(_, options)| options.traverse)
+            {
+                bail_spanned!(traverse.span() => "`traverse` requires the `#[pyclass(gc)]` option");
+            }
+            return Ok((None, Vec::new()));
+        }
+    };
+    ensure_spanned!(
+        options.frozen.is_none(),
+        gc.span() => "`gc` cannot be used on a `frozen` class, implement `__traverse__` and `__clear__` manually instead"
+    );
+
+    // `__clear__` can only release a reference by replacing it, which a bare `Py<T>` field can't be
+    for (field, options) in field_options {
+        if let (Some(traverse), true) = (options.traverse, is_bare_py(&field.ty)) {
+            bail_spanned!(
+                traverse.span() => "`traverse` cannot clear a bare `Py<T>` field, use `Option<Py<T>>` instead"
+            );
+        }
+    }
+
+    let fields: Vec<syn::Member> = field_options
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, options))| options.traverse.is_some())
+        .map(|(index, (field, _))| match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(index.into()),
+        })
+        .collect();
+
+    let mut traverse_impl: syn::ImplItemFn = parse_quote_spanned! { gc.span() =>
+        fn __pyo3__generated____traverse__(
+            &self,
+            visit: #pyo3_path::PyVisit<'_>,
+        ) -> ::std::result::Result<(), #pyo3_path::PyTraverseError> {
+            #(#pyo3_path::impl_::pyclass::TraverseField::traverse_field(&self.#fields, &visit)?;)*
+            ::std::result::Result::Ok(())
+        }
+    };
+    let mut clear_impl: syn::ImplItemFn = parse_quote_spanned! { gc.span() =>
+        fn __pyo3__generated____clear__(&mut self) {
+            #(#pyo3_path::impl_::pyclass::TraverseField::clear_field(&mut self.#fields);)*
+        }
+    };
+
+    let traverse_spec = FnSpec::parse(
+        &mut traverse_impl.sig,
+        &mut Vec::new(),
+        PyFunctionOptions::default(),
+    )?;
+    let clear_spec = FnSpec::parse(
+        &mut clear_impl.sig,
+        &mut Vec::new(),
+        PyFunctionOptions::default(),
+    )?;
+    let slots = vec![
+        impl_traverse_slot(cls, &traverse_spec, ctx)?,
+        impl_clear_slot(cls, &clear_spec, ctx)?,
+    ];
+
+    Ok((Some(quote! { #traverse_impl #clear_impl }), slots))
+}
+
+fn pyclass_match_args(
+    options: &PyClassPyO3Options,
+    field_options: &[(&syn::Field, FieldPyO3Options)],
+    cls: &syn::Type,
+    ctx: &Ctx,
+) -> Result<(Option<ImplItemFn>, Option<MethodAndMethodDef>)> {
+    let Ctx { pyo3_path, .. } = ctx;
+    let match_args = match &options.match_args {
+        Some(match_args) => match_args,
+        None => return Ok((None, None)),
+    };
+
+    let mut names = Vec::new();
+    for ident in &match_args.fields {
+        let (field, field_options) = field_options
+            .iter()
+            .find(|(field, _)| field.ident.as_ref() == Some(ident))
+            .ok_or_else(
+                || err_spanned!(ident.span() => format!("`{}` is not a field of this struct", ident)),
+            )?;
+        ensure_spanned!(
+            field_options.get.is_some(),
+            ident.span() => format!("`match_args` field `{}` requires a getter, e.g. `#[pyo3(get)]`", ident)
+        );
+        let name = match &field_options.name {
+            Some(name) => name.value.0.to_string(),
+            None => {
+                let name = field.ident.as_ref().unwrap().unraw().to_string();
+                match &options.rename_all {
+                    Some(rename_all) => utils::apply_renaming_rule(rename_all.value.rule, &name),
+                    None => name,
+                }
+            }
+        };
+        names.push(name);
+    }
+
+    let mut match_args_impl: ImplItemFn = parse_quote_spanned! { match_args.kw.span() =>
+        #[classattr]
+        fn __pyo3__generated____match_args__(
+            py: #pyo3_path::Python<'_>,
+        ) -> #pyo3_path::PyResult<#pyo3_path::Bound<'_, #pyo3_path::types::PyTuple>> {
+            #pyo3_path::types::PyTuple::new::<&str, _>(py, [#(#names,)*])
+        }
+    };
+    let spec = FnSpec::parse(
+        &mut match_args_impl.sig,
+        &mut match_args_impl.attrs,
+        PyFunctionOptions {
+            name: Some(NameAttribute {
+                kw: kw::name(match_args.kw.span),
+                value: NameLitStr(format_ident!("__match_args__")),
+            }),
+            ..Default::default()
+        },
+    )?;
+    let match_args_method = impl_py_class_attribute(cls, &spec, ctx)?;
+
+    Ok((Some(match_args_impl), Some(match_args_method)))
+}
+
 /// Implements most traits used by `#[pyclass]`.
 ///
 /// Specifically, it implements traits that only depend on class name,
@@ -2205,7 +2374,14 @@ impl<'a> PyClassImplsBuilder<'a> {
             TokenStream::new()
         };
 
-        let thread_checker = if self.attr.options.unsendable.is_some() {
+        ensure_spanned!(
+            self.attr.options.leak_on_foreign_drop.is_none() || self.attr.options.unsendable.is_some(),
+            self.attr.options.leak_on_foreign_drop.span() => "The `leak_on_foreign_drop` option requires the `unsendable` option."
+        );
+
+        let thread_checker = if self.attr.options.leak_on_foreign_drop.is_some() {
+            quote! { #pyo3_path::impl_::pyclass::LeakingThreadCheckerImpl }
+        } else if self.attr.options.unsendable.is_some() {
             quote! { #pyo3_path::impl_::pyclass::ThreadCheckerImpl }
         } else {
             quote! { #pyo3_path::impl_::pyclass::SendablePyClass<#cls> }
@@ -2290,6 +2466,14 @@ impl<'a> PyClassImplsBuilder<'a> {
             }
         });
 
+        let renaming_rule = attr.options.rename_all.as_ref().map(|rename_all| {
+            let rule = utils::renaming_rule_tokens(rename_all.value.rule, ctx);
+            quote! {
+                const RENAMING_RULE: ::std::option::Option<#pyo3_path::impl_::pyclass::RenamingRule> =
+                    ::std::option::Option::Some(#rule);
+            }
+        });
+
         let assertions = if attr.options.unsendable.is_some() {
             TokenStream::new()
         } else {
@@ -2311,6 +2495,7 @@ impl<'a> PyClassImplsBuilder<'a> {
                 const IS_SUBCLASS: bool = #is_subclass;
                 const IS_MAPPING: bool = #is_mapping;
                 const IS_SEQUENCE: bool = #is_sequence;
+                #renaming_rule
 
                 type BaseType = #base;
                 type ThreadChecker = #thread_checker;
@@ -2475,6 +2660,7 @@ fn generate_cfg_check(variants: &[PyClassEnumUnitVariant<'_>], cls: &syn::Ident)
 const UNIQUE_GET: &str = "`get` may only be specified once";
 const UNIQUE_SET: &str = "`set` may only be specified once";
 const UNIQUE_NAME: &str = "`name` may only be specified once";
+const UNIQUE_TRAVERSE: &str = "`traverse` may only be specified once";
 
 const DUPE_SET: &str = "useless `set` - the struct is already annotated with `set_all`";
 const DUPE_GET: &str = "useless `get` - the struct is already annotated with `get_all`";
@@ -1,7 +1,7 @@
 use crate::utils::Ctx;
 use crate::{
     attributes::{
-        self, get_pyo3_options, take_attributes, take_pyo3_options, CrateAttribute,
+        self, get_pyo3_options, take_attributes, take_pyo3_options, AliasAttribute, CrateAttribute,
         FromPyWithAttribute, NameAttribute, TextSignatureAttribute,
     },
     method::{self, CallingConvention, FnArg},
@@ -83,10 +83,11 @@ impl PyFunctionArgPyO3Attributes {
     }
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct PyFunctionOptions {
     pub pass_module: Option<attributes::kw::pass_module>,
     pub name: Option<NameAttribute>,
+    pub aliases: Vec<AliasAttribute>,
     pub signature: Option<SignatureAttribute>,
     pub text_signature: Option<TextSignatureAttribute>,
     pub krate: Option<CrateAttribute>,
@@ -121,6 +122,7 @@ impl Parse for PyFunctionOptions {
 
 pub enum PyFunctionOption {
     Name(NameAttribute),
+    Alias(AliasAttribute),
     PassModule(attributes::kw::pass_module),
     Signature(SignatureAttribute),
     TextSignature(TextSignatureAttribute),
@@ -132,6 +134,8 @@ impl Parse for PyFunctionOption {
         let lookahead = input.lookahead1();
         if lookahead.peek(attributes::kw::name) {
             input.parse().map(PyFunctionOption::Name)
+        } else if lookahead.peek(attributes::kw::alias) {
+            input.parse().map(PyFunctionOption::Alias)
         } else if lookahead.peek(attributes::kw::pass_module) {
             input.parse().map(PyFunctionOption::PassModule)
         } else if lookahead.peek(attributes::kw::signature) {
@@ -171,6 +175,7 @@ impl PyFunctionOptions {
         for attr in attrs {
             match attr {
                 PyFunctionOption::Name(name) => set_option!(name),
+                PyFunctionOption::Alias(alias) => self.aliases.push(alias),
                 PyFunctionOption::PassModule(pass_module) => set_option!(pass_module),
                 PyFunctionOption::Signature(signature) => set_option!(signature),
                 PyFunctionOption::TextSignature(text_signature) => set_option!(text_signature),
@@ -199,11 +204,16 @@ pub fn impl_wrap_pyfunction(
     let PyFunctionOptions {
         pass_module,
         name,
+        aliases,
         signature,
         text_signature,
         krate,
     } = options;
 
+    if let Some(alias) = aliases.first() {
+        bail_spanned!(alias.kw.span() => "`alias` is only supported for methods in `#[pymethods]`");
+    }
+
     let ctx = &Ctx::new(&krate, Some(&func.sig));
     let Ctx { pyo3_path, .. } = &ctx;
 
@@ -247,6 +257,7 @@ pub fn impl_wrap_pyfunction(
         name: &func.sig.ident,
         convention: CallingConvention::from_signature(&signature),
         python_name,
+        python_name_derived: false,
         signature,
         text_signature,
         asyncness: func.sig.asyncness,
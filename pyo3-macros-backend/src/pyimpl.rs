@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use crate::utils::{has_attribute, has_attribute_with_namespace, Ctx, PyO3CratePath};
 use crate::{
-    attributes::{take_pyo3_options, CrateAttribute},
+    attributes::{kw, take_pyo3_options, CrateAttribute, NameAttribute},
     konst::{ConstAttributes, ConstSpec},
     pyfunction::PyFunctionOptions,
     pymethod::{self, is_proto_method, MethodAndMethodDef, MethodAndSlotDef},
@@ -126,28 +126,55 @@ pub fn impl_methods(
 
                 check_pyfunction(&ctx.pyo3_path, meth)?;
 
-                match pymethod::gen_py_method(ty, &mut meth.sig, &mut meth.attrs, fun_options, ctx)?
-                {
-                    GeneratedPyMethod::Method(MethodAndMethodDef {
-                        associated_method,
-                        method_def,
-                    }) => {
-                        let attrs = get_cfg_attributes(&meth.attrs);
-                        associated_methods.push(quote!(#(#attrs)* #associated_method));
-                        methods.push(quote!(#(#attrs)* #method_def));
-                    }
-                    GeneratedPyMethod::SlotTraitImpl(method_name, token_stream) => {
-                        implemented_proto_fragments.insert(method_name);
-                        let attrs = get_cfg_attributes(&meth.attrs);
-                        trait_impls.push(quote!(#(#attrs)* #token_stream));
-                    }
-                    GeneratedPyMethod::Proto(MethodAndSlotDef {
-                        associated_method,
-                        slot_def,
-                    }) => {
-                        let attrs = get_cfg_attributes(&meth.attrs);
-                        proto_impls.push(quote!(#(#attrs)* #slot_def));
-                        associated_methods.push(quote!(#(#attrs)* #associated_method));
+                // aliases are generated as separate methods sharing the same signature and docs
+                let alias_methods: Vec<_> = fun_options
+                    .aliases
+                    .iter()
+                    .map(|alias| {
+                        let mut options = fun_options.clone();
+                        options.name = Some(NameAttribute {
+                            kw: kw::name(alias.kw.span),
+                            value: alias.value.clone(),
+                        });
+                        options.aliases.clear();
+                        (meth.sig.clone(), meth.attrs.clone(), options)
+                    })
+                    .collect();
+
+                let mut generated = vec![pymethod::gen_py_method(
+                    ty,
+                    &mut meth.sig,
+                    &mut meth.attrs,
+                    fun_options,
+                    ctx,
+                )?];
+                for (mut sig, mut attrs, options) in alias_methods {
+                    generated.push(pymethod::gen_py_method(
+                        ty, &mut sig, &mut attrs, options, ctx,
+                    )?);
+                }
+
+                let attrs = get_cfg_attributes(&meth.attrs);
+                for method in generated {
+                    match method {
+                        GeneratedPyMethod::Method(MethodAndMethodDef {
+                            associated_method,
+                            method_def,
+                        }) => {
+                            associated_methods.push(quote!(#(#attrs)* #associated_method));
+                            methods.push(quote!(#(#attrs)* #method_def));
+                        }
+                        GeneratedPyMethod::SlotTraitImpl(method_name, token_stream) => {
+                            implemented_proto_fragments.insert(method_name);
+                            trait_impls.push(quote!(#(#attrs)* #token_stream));
+                        }
+                        GeneratedPyMethod::Proto(MethodAndSlotDef {
+                            associated_method,
+                            slot_def,
+                        }) => {
+                            proto_impls.push(quote!(#(#attrs)* #slot_def));
+                            associated_methods.push(quote!(#(#attrs)* #associated_method));
+                        }
                     }
                 }
             }
@@ -194,10 +194,18 @@ pub fn gen_py_method(
 ) -> Result<GeneratedPyMethod> {
     check_generic(sig)?;
     ensure_function_options_valid(&options)?;
+    let alias_span = options.aliases.first().map(|alias| alias.kw.span);
     let method = PyMethod::parse(sig, meth_attrs, options)?;
     let spec = &method.spec;
     let Ctx { pyo3_path, .. } = ctx;
 
+    if let Some(span) = alias_span {
+        ensure_spanned!(
+            matches!(spec.tp, FnType::Fn(_) | FnType::FnClass(_) | FnType::FnStatic),
+            span => "`alias` can only be used on instance methods, class methods and static methods"
+        );
+    }
+
     Ok(match (method.kind, &spec.tp) {
         // Class attributes go before protos so that class attributes can be used to set proto
         // method to None.
@@ -429,7 +437,7 @@ fn impl_call_slot(cls: &syn::Type, mut spec: FnSpec<'_>, ctx: &Ctx) -> Result<Me
     })
 }
 
-fn impl_traverse_slot(
+pub(crate) fn impl_traverse_slot(
     cls: &syn::Type,
     spec: &FnSpec<'_>,
     ctx: &Ctx,
@@ -480,7 +488,11 @@ fn impl_traverse_slot(
     })
 }
 
-fn impl_clear_slot(cls: &syn::Type, spec: &FnSpec<'_>, ctx: &Ctx) -> syn::Result<MethodAndSlotDef> {
+pub(crate) fn impl_clear_slot(
+    cls: &syn::Type,
+    spec: &FnSpec<'_>,
+    ctx: &Ctx,
+) -> syn::Result<MethodAndSlotDef> {
     let Ctx { pyo3_path, .. } = ctx;
     let (py_arg, args) = split_off_python_arg(&spec.signature.arguments);
     let self_type = match &spec.tp {
@@ -610,7 +622,7 @@ pub fn impl_py_setter_def(
     ctx: &Ctx,
 ) -> Result<MethodAndMethodDef> {
     let Ctx { pyo3_path, .. } = ctx;
-    let python_name = property_type.null_terminated_python_name(ctx)?;
+    let python_name = property_type.null_terminated_python_name(cls, ctx)?;
     let doc = property_type.doc(ctx);
     let mut holders = Holders::new();
     let setter_impl = match property_type {
@@ -787,7 +799,7 @@ pub fn impl_py_getter_def(
     ctx: &Ctx,
 ) -> Result<MethodAndMethodDef> {
     let Ctx { pyo3_path, .. } = ctx;
-    let python_name = property_type.null_terminated_python_name(ctx)?;
+    let python_name = property_type.null_terminated_python_name(cls, ctx)?;
     let doc = property_type.doc(ctx);
 
     let mut cfg_attrs = TokenStream::new();
@@ -922,7 +934,7 @@ pub enum PropertyType<'a> {
 }
 
 impl PropertyType<'_> {
-    fn null_terminated_python_name(&self, ctx: &Ctx) -> Result<LitCStr> {
+    fn null_terminated_python_name(&self, cls: &syn::Type, ctx: &Ctx) -> Result<TokenStream> {
         match self {
             PropertyType::Descriptor {
                 field,
@@ -944,9 +956,36 @@ impl PropertyType<'_> {
                     }
                 };
                 let name = CString::new(name).unwrap();
-                Ok(LitCStr::new(name, field.span(), ctx))
+                Ok(LitCStr::new(name, field.span(), ctx).to_token_stream())
+            }
+            PropertyType::Function { spec, .. } if spec.python_name_derived => {
+                // The `rename_all` rule of the pyclass is only known through `PyClassImpl`, so
+                // select the renamed name at compile time from all the candidates.
+                let Ctx { pyo3_path, .. } = ctx;
+                let name = spec.python_name.to_string();
+                let renamed = RenamingRule::ALL.iter().filter_map(|rule| {
+                    let renamed = utils::apply_renaming_rule(*rule, &name);
+                    if renamed == name {
+                        return None;
+                    }
+                    let rule = utils::renaming_rule_tokens(*rule, ctx);
+                    let renamed =
+                        LitCStr::new(CString::new(renamed).unwrap(), spec.python_name.span(), ctx);
+                    Some(quote!(::std::option::Option::Some(#rule) => #renamed,))
+                });
+                let name = spec.null_terminated_python_name(ctx);
+                Ok(quote! {{
+                    const NAME: &::std::ffi::CStr =
+                        match <#cls as #pyo3_path::impl_::pyclass::PyClassImpl>::RENAMING_RULE {
+                            #(#renamed)*
+                            _ => #name,
+                        };
+                    NAME
+                }})
+            }
+            PropertyType::Function { spec, .. } => {
+                Ok(spec.null_terminated_python_name(ctx).to_token_stream())
             }
-            PropertyType::Function { spec, .. } => Ok(spec.null_terminated_python_name(ctx)),
         }
     }
 
@@ -1063,7 +1102,11 @@ const __IOR__: SlotDef = SlotDef::new("Py_nb_inplace_or", "binaryfunc")
 const __GETBUFFER__: SlotDef = SlotDef::new("Py_bf_getbuffer", "getbufferproc")
     .arguments(&[Ty::PyBuffer, Ty::Int])
     .ret_ty(Ty::Int)
-    .require_unsafe();
+    .require_unsafe()
+    // `arg1` is the `flags` argument
+    .precondition(TokenGenerator(|Ctx { pyo3_path, .. }: &Ctx| {
+        quote! { #pyo3_path::impl_::pyclass::check_buffer_writable::<Self>(arg1)?; }
+    }));
 const __RELEASEBUFFER__: SlotDef = SlotDef::new("Py_bf_releasebuffer", "releasebufferproc")
     .arguments(&[Ty::PyBuffer])
     .ret_ty(Ty::Void)
@@ -1256,6 +1299,7 @@ pub struct SlotDef {
     extract_error_mode: ExtractErrorMode,
     return_mode: Option<ReturnMode>,
     require_unsafe: bool,
+    precondition: Option<TokenGenerator>,
 }
 
 const NO_ARGUMENTS: &[Ty] = &[];
@@ -1270,6 +1314,7 @@ impl SlotDef {
             extract_error_mode: ExtractErrorMode::Raise,
             return_mode: None,
             require_unsafe: false,
+            precondition: None,
         }
     }
 
@@ -1312,6 +1357,13 @@ impl SlotDef {
         self
     }
 
+    /// Code run before extracting the arguments, which can refer to the class as `Self` and to
+    /// the raw slot arguments as `arg0`, `arg1`, ...
+    const fn precondition(mut self, precondition: TokenGenerator) -> Self {
+        self.precondition = Some(precondition);
+        self
+    }
+
     pub fn generate_type_slot(
         &self,
         cls: &syn::Type,
@@ -1328,6 +1380,7 @@ impl SlotDef {
             ret_ty,
             return_mode,
             require_unsafe,
+            precondition,
         } = self;
         if *require_unsafe {
             ensure_spanned!(
@@ -1353,6 +1406,7 @@ impl SlotDef {
         )?;
         let name = spec.name;
         let holders = holders.init_holders(ctx);
+        let precondition = precondition.map(|precondition| TokenGeneratorCtx(precondition, ctx));
         let associated_method = quote! {
             #[allow(non_snake_case)]
             unsafe fn #wrapper_ident(
@@ -1362,6 +1416,7 @@ impl SlotDef {
             ) -> #pyo3_path::PyResult<#ret_ty> {
                 let function = #cls::#name; // Shadow the method name to avoid #3017
                 let _slf = _raw_slf;
+                #precondition
                 #holders
                 #body
             }
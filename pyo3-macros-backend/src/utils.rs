@@ -288,6 +288,22 @@ pub fn apply_renaming_rule(rule: RenamingRule, name: &str) -> String {
     }
 }
 
+/// The matching variant of `pyo3::impl_::pyclass::RenamingRule`.
+pub fn renaming_rule_tokens(rule: RenamingRule, ctx: &Ctx) -> TokenStream {
+    let Ctx { pyo3_path, .. } = ctx;
+    let variant = match rule {
+        RenamingRule::CamelCase => quote!(CamelCase),
+        RenamingRule::KebabCase => quote!(KebabCase),
+        RenamingRule::Lowercase => quote!(Lowercase),
+        RenamingRule::PascalCase => quote!(PascalCase),
+        RenamingRule::ScreamingKebabCase => quote!(ScreamingKebabCase),
+        RenamingRule::ScreamingSnakeCase => quote!(ScreamingSnakeCase),
+        RenamingRule::SnakeCase => quote!(SnakeCase),
+        RenamingRule::Uppercase => quote!(Uppercase),
+    };
+    quote!(#pyo3_path::impl_::pyclass::RenamingRule::#variant)
+}
+
 pub(crate) enum IdentOrStr<'a> {
     Str(&'a str),
     Ident(syn::Ident),
@@ -337,3 +353,30 @@ pub(crate) fn deprecated_from_py_with(expr_path: &ExprPathWrap) -> Option<TokenS
         }
     })
 }
+
+/// Returns the integer type of an enum's discriminants, as given by its `#[repr]` attribute.
+pub(crate) fn enum_repr_type(attrs: &[syn::Attribute]) -> syn::Result<syn::Ident> {
+    fn is_numeric_type(t: &syn::Ident) -> bool {
+        [
+            "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize",
+        ]
+        .iter()
+        .any(|&s| t == s)
+    }
+
+    // According to the [reference](https://doc.rust-lang.org/reference/items/enumerations.html),
+    // "Under the default representation, the specified discriminant is interpreted as an isize
+    // value", so `isize` should be enough by default.
+    let mut repr_type = syn::Ident::new("isize", Span::call_site());
+    if let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("repr")) {
+        let args = attr.parse_args_with(Punctuated::<TokenStream, Token![!]>::parse_terminated)?;
+        if let Some(ident) = args
+            .into_iter()
+            .filter_map(|ts| syn::parse2::<syn::Ident>(ts).ok())
+            .find(is_numeric_type)
+        {
+            repr_type = ident;
+        }
+    }
+    Ok(repr_type)
+}
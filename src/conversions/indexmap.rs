@@ -171,9 +171,20 @@ where
     S: hash::BuildHasher + Default,
 {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> Result<Self, PyErr> {
-        let dict = ob.downcast::<PyDict>()?;
-        let mut ret = indexmap::IndexMap::with_capacity_and_hasher(dict.len(), S::default());
-        for (k, v) in dict {
+        if let Ok(dict) = ob.downcast::<PyDict>() {
+            let mut ret = indexmap::IndexMap::with_capacity_and_hasher(dict.len(), S::default());
+            for (k, v) in dict {
+                ret.insert(k.extract()?, v.extract()?);
+            }
+            return Ok(ret);
+        }
+
+        // other mappings are read through `.items()`, keeping their iteration order
+        let mapping = ob.downcast::<PyMapping>()?;
+        let items = mapping.items()?;
+        let mut ret = indexmap::IndexMap::with_capacity_and_hasher(items.len(), S::default());
+        for item in items {
+            let (k, v): (Bound<'py, PyAny>, Bound<'py, PyAny>) = item.extract()?;
             ret.insert(k.extract()?, v.extract()?);
         }
         Ok(ret)
@@ -232,6 +243,37 @@ mod test_indexmap {
         });
     }
 
+    #[test]
+    fn test_indexmap_extract_from_mapping() {
+        Python::with_gil(|py| {
+            let mapping = py
+                .eval(
+                    crate::ffi::c_str!(
+                        "__import__('types').MappingProxyType({'c': 3, 'a': 1, 'b': 2})"
+                    ),
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert!(!mapping.is_instance_of::<PyDict>());
+
+            let map = mapping
+                .extract::<indexmap::IndexMap<String, i32>>()
+                .unwrap();
+            assert_eq!(
+                map.into_iter().collect::<Vec<_>>(),
+                [
+                    ("c".to_owned(), 3),
+                    ("a".to_owned(), 1),
+                    ("b".to_owned(), 2)
+                ]
+            );
+
+            let list = PyList::new(py, [1, 2]).unwrap();
+            assert!(list.extract::<indexmap::IndexMap<i32, i32>>().is_err());
+        });
+    }
+
     #[test]
     fn test_indexmap_indexmap_insertion_order_round_trip() {
         Python::with_gil(|py| {
@@ -13,6 +13,7 @@ pub mod num_complex;
 pub mod num_rational;
 pub mod rust_decimal;
 pub mod serde;
+pub mod serde_json;
 pub mod smallvec;
 mod std;
 pub mod uuid;
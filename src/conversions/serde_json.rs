@@ -0,0 +1,192 @@
+#![cfg(feature = "serde_json")]
+
+//! Conversions from [serde_json](https://docs.rs/serde_json)'s [`Value`] type.
+//!
+//! This is useful for passing arbitrary JSON-shaped data into Python without round-tripping
+//! through a JSON string.
+//!
+//! # Setup
+//!
+//! To use this feature, add this to your **`Cargo.toml`**:
+//!
+//! ```toml
+//! [dependencies]
+#![doc = concat!("pyo3 = { version = \"", env!("CARGO_PKG_VERSION"),  "\", features = [\"serde_json\"] }")]
+//! serde_json = "1.0"
+//! ```
+//!
+//! Note that you must use compatible versions of serde_json and PyO3.
+//! The required serde_json version may vary based on the version of PyO3.
+//!
+//! # Conversion
+//!
+//! A [`Value`] is converted recursively:
+//!
+//! | Rust                  | Python  |
+//! | --------------------- | ------- |
+//! | `Value::Null`         | `None`  |
+//! | `Value::Bool`         | `bool`  |
+//! | `Value::Number`       | `int` if the number is an integer, `float` otherwise |
+//! | `Value::String`       | `str`   |
+//! | `Value::Array`        | `list`  |
+//! | `Value::Object`       | `dict`  |
+//!
+//! On Python 3.9 and up, converting a value nested deeper than the interpreter's recursion limit
+//! raises `RecursionError` instead of overflowing the stack.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pyo3::prelude::*;
+//! use serde_json::Value;
+//!
+//! /// Parse a JSON document into Python objects.
+//! #[pyfunction]
+//! fn load_config(text: &str) -> PyResult<Value> {
+//!     serde_json::from_str(text)
+//!         .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+//! }
+//!
+//! #[pymodule]
+//! fn my_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+//!     m.add_function(wrap_pyfunction!(load_config, m)?)?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::conversion::IntoPyObject;
+use crate::exceptions::PyValueError;
+use crate::types::{PyDict, PyDictMethods, PyList, PyListMethods};
+use crate::{Bound, IntoPyObjectExt, PyAny, PyErr, PyResult, Python};
+use serde_json::{Number, Value};
+
+fn number_into_pyobject<'py>(py: Python<'py>, number: &Number) -> PyResult<Bound<'py, PyAny>> {
+    if let Some(int) = number.as_i64() {
+        int.into_bound_py_any(py)
+    } else if let Some(int) = number.as_u64() {
+        int.into_bound_py_any(py)
+    } else if let Some(float) = number.as_f64() {
+        float.into_bound_py_any(py)
+    } else {
+        Err(PyValueError::new_err(format!(
+            "cannot convert JSON number {} to a Python object",
+            number
+        )))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for Value {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (&self).into_pyobject(py)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &Value {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        #[cfg(Py_3_9)]
+        let _guard =
+            py.enter_recursive_call(crate::ffi::c_str!(" while converting serde_json::Value"))?;
+
+        match self {
+            Value::Null => Ok(py.None().into_bound(py)),
+            Value::Bool(b) => b.into_bound_py_any(py),
+            Value::Number(number) => number_into_pyobject(py, number),
+            Value::String(string) => string.into_bound_py_any(py),
+            Value::Array(array) => {
+                let list = PyList::empty(py);
+                for value in array {
+                    list.append(value)?;
+                }
+                Ok(list.into_any())
+            }
+            Value::Object(object) => {
+                let dict = PyDict::new(py);
+                for (key, value) in object {
+                    dict.set_item(key, value)?;
+                }
+                Ok(dict.into_any())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi;
+    use crate::types::PyAnyMethods;
+    use serde_json::json;
+
+    #[test]
+    fn test_nested_object() {
+        Python::with_gil(|py| {
+            let value = json!({
+                "name": "pyo3",
+                "stars": 12000,
+                "ratio": 0.5,
+                "big": u64::MAX,
+                "negative": -3,
+                "archived": false,
+                "license": null,
+                "tags": ["rust", "python", {"nested": [1, 2.5]}],
+            });
+            let obj = value.into_pyobject(py).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("obj", obj).unwrap();
+            py.run(
+                ffi::c_str!(
+                    r#"
+assert obj == {
+    "name": "pyo3",
+    "stars": 12000,
+    "ratio": 0.5,
+    "big": 2**64 - 1,
+    "negative": -3,
+    "archived": False,
+    "license": None,
+    "tags": ["rust", "python", {"nested": [1, 2.5]}],
+}
+assert type(obj["stars"]) is int
+assert type(obj["ratio"]) is float
+assert type(obj["tags"][2]["nested"][0]) is int
+assert type(obj["tags"][2]["nested"][1]) is float
+"#
+                ),
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn test_array() {
+        Python::with_gil(|py| {
+            let value = json!([null, true, "a", [], {}]);
+            let obj = (&value).into_pyobject(py).unwrap();
+            assert_eq!(obj.repr().unwrap(), "[None, True, 'a', [], {}]");
+        });
+    }
+
+    #[test]
+    #[cfg(Py_3_9)]
+    fn test_deeply_nested() {
+        Python::with_gil(|py| {
+            let mut value = Value::Null;
+            for _ in 0..10_000 {
+                value = Value::Array(vec![value]);
+            }
+            let err = value.into_pyobject(py).unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyRecursionError>(py));
+        });
+    }
+}
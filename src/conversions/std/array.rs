@@ -246,6 +246,34 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_extract_list_and_tuple_to_array() {
+        Python::with_gil(|py| {
+            let v: [i32; 3] = py
+                .eval(ffi::c_str!("[1, 2, 3]"), None, None)
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(v, [1, 2, 3]);
+
+            let v: [String; 2] = py
+                .eval(ffi::c_str!("('a', 'b')"), None, None)
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(v, ["a", "b"]);
+
+            let v: PyResult<[i32; 3]> = py
+                .eval(ffi::c_str!("[1, 2]"), None, None)
+                .unwrap()
+                .extract();
+            assert_eq!(
+                v.unwrap_err().to_string(),
+                "ValueError: expected a sequence of length 3 (got 2)"
+            );
+        })
+    }
+
     #[test]
     fn test_intopyobject_array_conversion() {
         Python::with_gil(|py| {
@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+
+use crate::{conversion::IntoPyObject, Bound, IntoPyObjectExt, PyAny, PyErr, Python};
+
+/// Converts the borrowed variant like `&T` and the owned variant like `T`.
+///
+/// This covers `Cow`s of sized types. `Cow<str>`, `Cow<[T]>`, `Cow<OsStr>` and `Cow<Path>` have
+/// their own conversions.
+impl<'py, T> IntoPyObject<'py> for Cow<'_, T>
+where
+    T: Clone + IntoPyObject<'py>,
+    for<'a> &'a T: IntoPyObject<'py>,
+{
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        match self {
+            Cow::Borrowed(borrowed) => borrowed.into_bound_py_any(py),
+            Cow::Owned(owned) => owned.into_bound_py_any(py),
+        }
+    }
+}
+
+impl<'a, 'py, T> IntoPyObject<'py> for &'a Cow<'_, T>
+where
+    T: Clone,
+    &'a T: IntoPyObject<'py>,
+{
+    type Target = <&'a T as IntoPyObject<'py>>::Target;
+    type Output = <&'a T as IntoPyObject<'py>>::Output;
+    type Error = <&'a T as IntoPyObject<'py>>::Error;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (**self).into_pyobject(py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    use crate::types::{PyAnyMethods, PyDict, PyInt};
+    use crate::{IntoPyObject, Python};
+
+    #[test]
+    fn test_cow_into_pyobject() {
+        Python::with_gil(|py| {
+            let value = 42u32;
+            let borrowed = Cow::Borrowed(&value).into_pyobject(py).unwrap();
+            assert!(borrowed.is_instance_of::<PyInt>());
+            assert_eq!(borrowed.extract::<u32>().unwrap(), 42);
+
+            let owned = Cow::<u32>::Owned(7).into_pyobject(py).unwrap();
+            assert_eq!(owned.extract::<u32>().unwrap(), 7);
+
+            let map = HashMap::from([("a", 1)]);
+            let cow = Cow::Borrowed(&map);
+            let dict = (&cow).into_pyobject(py).unwrap();
+            assert_eq!(dict.extract::<HashMap<String, i32>>().unwrap()["a"], 1);
+
+            let dict = cow.into_pyobject(py).unwrap();
+            assert!(dict.is_instance_of::<PyDict>());
+        });
+    }
+}
@@ -1,11 +1,13 @@
 mod array;
 mod cell;
+mod cow;
 mod ipaddr;
 mod map;
 mod num;
 mod option;
 mod osstr;
 mod path;
+mod range;
 mod set;
 mod slice;
 mod string;
@@ -666,7 +666,7 @@ macro_rules! nonzero_int_impl {
             fn extract_bound(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
                 let val: $primitive_type = obj.extract()?;
                 <$nonzero_type>::try_from(val)
-                    .map_err(|_| exceptions::PyValueError::new_err("invalid zero value"))
+                    .map_err(|_| exceptions::PyValueError::new_err("expected a non-zero integer"))
             }
 
             #[cfg(feature = "experimental-inspect")]
@@ -1060,6 +1060,15 @@ mod tests {
                     assert!(err.is_instance_of::<exceptions::PyTypeError>(py));});
                 }
 
+                #[test]
+                fn from_py_zero_value_error() {
+                    Python::with_gil(|py| {
+                    let obj = 0i32.into_pyobject(py).unwrap();
+                    let err = obj.extract::<$t>().unwrap_err();
+                    assert!(err.is_instance_of::<exceptions::PyValueError>(py));
+                    assert_eq!(err.value(py).to_string(), "expected a non-zero integer");});
+                }
+
                 #[test]
                 fn to_py_object_and_back() {
                     Python::with_gil(|py| {
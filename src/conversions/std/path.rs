@@ -147,7 +147,7 @@ impl<'py> IntoPyObject<'py> for &PathBuf {
 
 #[cfg(test)]
 mod tests {
-    use crate::types::{PyAnyMethods, PyString, PyStringMethods};
+    use crate::types::{PyAnyMethods, PyDictMethods, PyString, PyStringMethods};
     use crate::{IntoPyObject, IntoPyObjectExt, PyObject, Python};
     use std::borrow::Cow;
     use std::fmt::Debug;
@@ -194,6 +194,43 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_into_pathlib_and_from_pathlike() {
+        Python::with_gil(|py| {
+            let path = Path::new("some/dir/file.txt");
+            let py_path = path.into_pyobject(py).unwrap();
+            let pathlib_path = py.import("pathlib").unwrap().getattr("Path").unwrap();
+            assert!(py_path.is_instance(&pathlib_path).unwrap());
+            assert_eq!(
+                py_path
+                    .getattr("name")
+                    .unwrap()
+                    .extract::<String>()
+                    .unwrap(),
+                "file.txt"
+            );
+
+            let locals = crate::types::PyDict::new(py);
+            py.run(
+                crate::ffi::c_str!(
+                    r#"
+class CustomPath:
+    def __fspath__(self):
+        return "some/dir/file.txt"
+custom = CustomPath()
+"#
+                ),
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+            let custom = locals.get_item("custom").unwrap().unwrap();
+            assert_eq!(custom.extract::<PathBuf>().unwrap(), path);
+
+            assert!(py.None().extract::<PathBuf>(py).is_err());
+        });
+    }
+
     #[test]
     fn test_from_pystring() {
         Python::with_gil(|py| {
@@ -0,0 +1,179 @@
+use std::ops::{Range, RangeInclusive};
+
+use crate::conversion::IntoPyObject;
+use crate::exceptions::{PyOverflowError, PyValueError};
+use crate::instance::Bound;
+use crate::types::any::PyAnyMethods;
+use crate::types::PyType;
+use crate::{ffi, intern, DowncastError, FromPyObject, PyAny, PyErr, PyResult, Python};
+
+/// Extracts the `start` and `stop` of a Python `range` whose `step` is 1.
+fn extract_range_bounds<'py, T>(obj: &Bound<'py, PyAny>) -> PyResult<(i64, T)>
+where
+    T: FromPyObject<'py>,
+{
+    if unsafe { ffi::PyRange_Check(obj.as_ptr()) } == 0 {
+        return Err(DowncastError::new(obj, "range").into());
+    }
+    let py = obj.py();
+    let step: i64 = obj.getattr(intern!(py, "step"))?.extract()?;
+    if step != 1 {
+        return Err(PyValueError::new_err(format!(
+            "cannot convert a range with step {} to a Rust range, only a step of 1 is supported",
+            step
+        )));
+    }
+    Ok((
+        obj.getattr(intern!(py, "start"))?.extract()?,
+        obj.getattr(intern!(py, "stop"))?.extract()?,
+    ))
+}
+
+fn new_range<'py>(
+    py: Python<'py>,
+    start: i64,
+    stop: impl IntoPyObject<'py>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let range =
+        unsafe { PyType::from_borrowed_type_ptr(py, std::ptr::addr_of_mut!(ffi::PyRange_Type)) };
+    range.call1((start, stop))
+}
+
+impl FromPyObject<'_> for Range<i64> {
+    fn extract_bound(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let (start, stop) = extract_range_bounds::<i64>(obj)?;
+        Ok(start..stop)
+    }
+}
+
+impl FromPyObject<'_> for RangeInclusive<i64> {
+    fn extract_bound(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        // `stop` may be one past `i64::MAX` for ranges ending at `i64::MAX`
+        let (start, stop) = extract_range_bounds::<i128>(obj)?;
+        let end = i64::try_from(stop - 1).map_err(|_| {
+            PyOverflowError::new_err("range stop is out of range for a Rust inclusive range")
+        })?;
+        Ok(start..=end)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for Range<i64> {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        new_range(py, self.start, self.end)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &Range<i64> {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        self.clone().into_pyobject(py)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for RangeInclusive<i64> {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (&self).into_pyobject(py)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &RangeInclusive<i64> {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let start = *self.start();
+        // An exhausted iterator reports `start == end` but is empty, so it maps to an empty range.
+        let stop = if self.is_empty() && start <= *self.end() {
+            i128::from(start)
+        } else {
+            i128::from(*self.end()) + 1
+        };
+        new_range(py, start, stop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi;
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_range_roundtrip() {
+        Python::with_gil(|py| {
+            for range in [0..10, -5..5, 5..3, 0..0, i64::MIN..i64::MAX] {
+                let obj = range.clone().into_pyobject(py).unwrap();
+                assert_eq!(obj.extract::<Range<i64>>().unwrap(), range);
+            }
+        });
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_range_inclusive_roundtrip() {
+        Python::with_gil(|py| {
+            for range in [0..=10, -5..=5, 5..=3, 0..=-1, i64::MIN..=i64::MAX] {
+                let obj = range.clone().into_pyobject(py).unwrap();
+                assert_eq!(obj.extract::<RangeInclusive<i64>>().unwrap(), range);
+            }
+
+            let mut exhausted = 0..=0;
+            exhausted.next();
+            let obj = exhausted.into_pyobject(py).unwrap();
+            assert_eq!(obj.len().unwrap(), 0);
+        });
+    }
+
+    #[test]
+    fn test_range_from_python() {
+        Python::with_gil(|py| {
+            let obj = py.eval(ffi::c_str!("range(2, 7)"), None, None).unwrap();
+            assert_eq!(obj.extract::<Range<i64>>().unwrap(), 2..7);
+            assert_eq!(obj.extract::<RangeInclusive<i64>>().unwrap(), 2..=6);
+
+            let obj = py.eval(ffi::c_str!("range(7)"), None, None).unwrap();
+            assert_eq!(obj.extract::<Range<i64>>().unwrap(), 0..7);
+        });
+    }
+
+    #[test]
+    fn test_range_with_step() {
+        Python::with_gil(|py| {
+            for code in [
+                ffi::c_str!("range(0, 10, 2)"),
+                ffi::c_str!("range(10, 0, -1)"),
+            ] {
+                let obj = py.eval(code, None, None).unwrap();
+                let err = obj.extract::<Range<i64>>().unwrap_err();
+                assert!(err.is_instance_of::<PyValueError>(py));
+                let err = obj.extract::<RangeInclusive<i64>>().unwrap_err();
+                assert!(err.is_instance_of::<PyValueError>(py));
+            }
+        });
+    }
+
+    #[test]
+    fn test_range_from_non_range() {
+        Python::with_gil(|py| {
+            let obj = py.eval(ffi::c_str!("[0, 1, 2]"), None, None).unwrap();
+            let err = obj.extract::<Range<i64>>().unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "TypeError: 'list' object cannot be converted to 'range'"
+            );
+        });
+    }
+}
@@ -55,19 +55,29 @@ impl<'a> crate::conversion::FromPyObjectBound<'a, '_> for &'a [u8] {
     }
 }
 
-/// Special-purpose trait impl to efficiently handle both `bytes` and `bytearray`
+/// Special-purpose trait impl to efficiently handle `bytes`, `bytearray` and other buffers
 ///
 /// If the source object is a `bytes` object, the `Cow` will be borrowed and
 /// pointing into the source object, and no copying or heap allocations will happen.
-/// If it is a `bytearray`, its contents will be copied to an owned `Cow`.
+/// If it is a `bytearray`, or any other object exporting a byte buffer such as a `memoryview`,
+/// its contents will be copied to an owned `Cow`.
 impl<'a> crate::conversion::FromPyObjectBound<'a, '_> for Cow<'a, [u8]> {
     fn from_py_object_bound(ob: crate::Borrowed<'a, '_, PyAny>) -> PyResult<Self> {
         if let Ok(bytes) = ob.downcast::<PyBytes>() {
             return Ok(Cow::Borrowed(bytes.as_bytes()));
         }
 
-        let byte_array = ob.downcast::<PyByteArray>()?;
-        Ok(Cow::Owned(byte_array.to_vec()))
+        let err = match ob.downcast::<PyByteArray>() {
+            Ok(byte_array) => return Ok(Cow::Owned(byte_array.to_vec())),
+            Err(err) => err,
+        };
+
+        #[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+        if let Ok(buffer) = crate::buffer::PyBuffer::<u8>::get(&ob) {
+            return buffer.to_vec(ob.py()).map(Cow::Owned);
+        }
+
+        Err(err.into())
     }
 
     #[cfg(feature = "experimental-inspect")]
@@ -142,6 +152,15 @@ mod tests {
             let cow = byte_array.extract::<Cow<'_, [u8]>>().unwrap();
             assert_eq!(cow, Cow::<[u8]>::Owned(b"foobar".to_vec()));
 
+            #[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+            {
+                let memory_view = py
+                    .eval(ffi::c_str!(r#"memoryview(b"foobar")[1:4]"#), None, None)
+                    .unwrap();
+                let cow = memory_view.extract::<Cow<'_, [u8]>>().unwrap();
+                assert_eq!(cow, Cow::<[u8]>::Owned(b"oob".to_vec()));
+            }
+
             let something_else_entirely = py.eval(ffi::c_str!("42"), None, None).unwrap();
             something_else_entirely
                 .extract::<Cow<'_, [u8]>>()
@@ -349,6 +349,26 @@ mod tests {
                 .unwrap_err()
                 .to_string()
                 .contains("expected a string of length 1"));
+
+            // empty strings and multi-codepoint graphemes are not a single `char`
+            for s in ["", "e\u{301}", "🇬🇧"] {
+                let err = s.into_pyobject(py).unwrap().extract::<char>().unwrap_err();
+                assert!(err.is_instance_of::<crate::exceptions::PyValueError>(py));
+                assert!(err.to_string().contains("expected a string of length 1"));
+            }
+
+            // a surrogate pair spelled out in Python is two code points, and a lone surrogate is
+            // not a Unicode scalar value
+            for code in [
+                crate::ffi::c_str!("'\\ud83d\\ude03'"),
+                crate::ffi::c_str!("'\\ud83d'"),
+            ] {
+                let py_string = py.eval(code, None, None).unwrap();
+                let err = py_string.extract::<char>().unwrap_err();
+                assert!(err.is_instance_of::<crate::exceptions::PyValueError>(py));
+            }
+
+            assert!(1i32.into_pyobject(py).unwrap().extract::<char>().is_err());
         })
     }
 
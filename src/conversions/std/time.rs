@@ -6,6 +6,7 @@ use crate::types::any::PyAnyMethods;
 use crate::types::PyType;
 #[cfg(not(Py_LIMITED_API))]
 use crate::types::{timezone_utc, PyDateTime, PyDelta, PyDeltaAccess};
+use crate::types::{PyDict, PyDictMethods};
 #[cfg(Py_LIMITED_API)]
 use crate::Py;
 use crate::{intern, Bound, FromPyObject, PyAny, PyErr, PyObject, PyResult, Python};
@@ -123,14 +124,36 @@ impl<'py> IntoPyObject<'py> for &Duration {
 
 impl FromPyObject<'_> for SystemTime {
     fn extract_bound(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
-        let duration_since_unix_epoch: Duration = obj
-            .call_method1(intern!(obj.py(), "__sub__"), (unix_epoch_py(obj.py())?,))?
-            .extract()?;
-        UNIX_EPOCH
-            .checked_add(duration_since_unix_epoch)
-            .ok_or_else(|| {
-                PyOverflowError::new_err("Overflow error when converting the time to Rust")
-            })
+        let py = obj.py();
+        // Naive datetimes are interpreted as being in UTC.
+        let aware;
+        let obj = if obj.getattr(intern!(py, "tzinfo"))?.is_none() {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item(intern!(py, "tzinfo"), utc_py(py)?)?;
+            aware = obj.call_method(intern!(py, "replace"), (), Some(&kwargs))?;
+            &aware
+        } else {
+            obj
+        };
+
+        let unix_epoch = unix_epoch_py(py)?.bind(py);
+        if obj.lt(unix_epoch)? {
+            let duration_before_unix_epoch: Duration = unix_epoch.sub(obj)?.extract()?;
+            UNIX_EPOCH
+                .checked_sub(duration_before_unix_epoch)
+                .ok_or_else(|| {
+                    PyValueError::new_err(
+                        "The time is before the UNIX epoch and cannot be represented on this platform",
+                    )
+                })
+        } else {
+            let duration_since_unix_epoch: Duration = obj.sub(unix_epoch)?.extract()?;
+            UNIX_EPOCH
+                .checked_add(duration_since_unix_epoch)
+                .ok_or_else(|| {
+                    PyOverflowError::new_err("Overflow error when converting the time to Rust")
+                })
+        }
     }
 }
 
@@ -151,20 +174,38 @@ impl IntoPy<PyObject> for SystemTime {
 }
 
 impl<'py> IntoPyObject<'py> for SystemTime {
+    #[cfg(not(Py_LIMITED_API))]
+    type Target = PyDateTime;
+    #[cfg(Py_LIMITED_API)]
     type Target = PyAny;
     type Output = Bound<'py, Self::Target>;
     type Error = PyErr;
 
     fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
-        let duration_since_unix_epoch =
-            self.duration_since(UNIX_EPOCH).unwrap().into_pyobject(py)?;
-        unix_epoch_py(py)?
-            .bind(py)
-            .call_method1(intern!(py, "__add__"), (duration_since_unix_epoch,))
+        let unix_epoch = unix_epoch_py(py)?.bind(py);
+        let datetime = match self.duration_since(UNIX_EPOCH) {
+            Ok(duration_since_unix_epoch) => {
+                unix_epoch.add(duration_since_unix_epoch.into_pyobject(py)?)?
+            }
+            Err(err) => unix_epoch.sub(err.duration().into_pyobject(py)?)?,
+        };
+
+        #[cfg(not(Py_LIMITED_API))]
+        {
+            // Safety: adding a timedelta to a datetime always produces a datetime
+            Ok(unsafe { datetime.downcast_into_unchecked() })
+        }
+        #[cfg(Py_LIMITED_API)]
+        {
+            Ok(datetime)
+        }
     }
 }
 
 impl<'py> IntoPyObject<'py> for &SystemTime {
+    #[cfg(not(Py_LIMITED_API))]
+    type Target = PyDateTime;
+    #[cfg(Py_LIMITED_API)]
     type Target = PyAny;
     type Output = Bound<'py, Self::Target>;
     type Error = PyErr;
@@ -175,6 +216,27 @@ impl<'py> IntoPyObject<'py> for &SystemTime {
     }
 }
 
+#[cfg_attr(not(Py_LIMITED_API), allow(clippy::unnecessary_wraps))]
+fn utc_py(py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+    #[cfg(not(Py_LIMITED_API))]
+    {
+        Ok(timezone_utc(py).into_any())
+    }
+    #[cfg(Py_LIMITED_API)]
+    {
+        static UTC: GILOnceCell<PyObject> = GILOnceCell::new();
+        UTC.get_or_try_init(py, || {
+            Ok::<_, PyErr>(
+                py.import("datetime")?
+                    .getattr("timezone")?
+                    .getattr("utc")?
+                    .unbind(),
+            )
+        })
+        .map(|utc| utc.bind(py).clone())
+    }
+}
+
 fn unix_epoch_py(py: Python<'_>) -> PyResult<&PyObject> {
     static UNIX_EPOCH: GILOnceCell<PyObject> = GILOnceCell::new();
     UNIX_EPOCH.get_or_try_init(py, || {
@@ -187,12 +249,10 @@ fn unix_epoch_py(py: Python<'_>) -> PyResult<&PyObject> {
         #[cfg(Py_LIMITED_API)]
         {
             let datetime = py.import("datetime")?;
-            let utc = datetime.getattr("timezone")?.getattr("utc")?;
             Ok::<_, PyErr>(
                 datetime
                     .getattr("datetime")?
-                    .call1((1970, 1, 1, 0, 0, 0, 0, utc))
-                    .unwrap()
+                    .call1((1970, 1, 1, 0, 0, 0, 0, utc_py(py)?))?
                     .into(),
             )
         }
@@ -202,7 +262,6 @@ fn unix_epoch_py(py: Python<'_>) -> PyResult<&PyObject> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::PyDict;
 
     #[test]
     fn test_duration_frompyobject() {
@@ -340,9 +399,21 @@ mod tests {
             assert_eq!(
                 new_datetime(py, 1950, 1, 1, 0, 0, 0, 0)
                     .extract::<SystemTime>()
-                    .unwrap_err()
-                    .to_string(),
-                "ValueError: It is not possible to convert a negative timedelta to a Rust Duration"
+                    .unwrap(),
+                UNIX_EPOCH.checked_sub(Duration::new(631152000, 0)).unwrap()
+            );
+        })
+    }
+
+    #[test]
+    fn test_time_frompyobject_naive() {
+        Python::with_gil(|py| {
+            let naive = datetime_class(py).call1((2020, 2, 3, 4, 5, 6, 7)).unwrap();
+            assert_eq!(
+                naive.extract::<SystemTime>().unwrap(),
+                UNIX_EPOCH
+                    .checked_add(Duration::new(1580702706, 7000))
+                    .unwrap()
             );
         })
     }
@@ -359,7 +430,8 @@ mod tests {
                     .checked_add(Duration::new(1580702706, 7123))
                     .unwrap()
                     .into_pyobject(py)
-                    .unwrap(),
+                    .unwrap()
+                    .into_any(),
                 new_datetime(py, 2020, 2, 3, 4, 5, 6, 7),
             );
             assert_eq(
@@ -367,9 +439,19 @@ mod tests {
                     .checked_add(Duration::new(253402300799, 999999000))
                     .unwrap()
                     .into_pyobject(py)
-                    .unwrap(),
+                    .unwrap()
+                    .into_any(),
                 max_datetime(py),
             );
+            assert_eq(
+                UNIX_EPOCH
+                    .checked_sub(Duration::new(631152000, 0))
+                    .unwrap()
+                    .into_pyobject(py)
+                    .unwrap()
+                    .into_any(),
+                new_datetime(py, 1950, 1, 1, 0, 0, 0, 0),
+            );
         });
     }
 
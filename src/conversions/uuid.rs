@@ -85,7 +85,7 @@ impl FromPyObject<'_> for Uuid {
 
         if obj.is_instance(uuid_cls)? {
             let uuid_int: u128 = obj.getattr(intern!(py, "int"))?.extract()?;
-            Ok(Uuid::from_u128(uuid_int.to_le()))
+            Ok(Uuid::from_u128(uuid_int))
         } else {
             Err(PyTypeError::new_err("Expected a `uuid.UUID` instance."))
         }
@@ -181,4 +181,35 @@ mod tests {
         Uuid::parse_str("a6cc5730-2261-11ee-9c43-2eb5a363657c").unwrap(),
         "a6cc5730-2261-11ee-9c43-2eb5a363657c"
     );
+
+    #[test]
+    fn test_uuid_extract_subclass() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new(py);
+            py.run(
+                crate::ffi::c_str!(
+                    "import uuid\nclass MyUUID(uuid.UUID): pass\nobj = MyUUID('a4f6d1b9-1898-418f-b11d-ecc6fe1e1f00')"
+                ),
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+            let obj = locals.get_item("obj").unwrap().unwrap();
+            assert_eq!(
+                obj.extract::<Uuid>().unwrap(),
+                Uuid::parse_str("a4f6d1b9-1898-418f-b11d-ecc6fe1e1f00").unwrap()
+            );
+        });
+    }
+
+    #[test]
+    fn test_uuid_extract_wrong_type() {
+        Python::with_gil(|py| {
+            let obj = "a4f6d1b9-1898-418f-b11d-ecc6fe1e1f00"
+                .into_pyobject(py)
+                .unwrap();
+            let err = obj.extract::<Uuid>().unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
 }
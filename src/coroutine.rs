@@ -103,7 +103,7 @@ impl Coroutine {
             }
             Err(err) => {
                 self.close();
-                return Err(PanicException::from_panic_payload(err));
+                return Err(PanicException::from_panic_payload(py, err));
             }
             _ => {}
         }
@@ -147,10 +147,11 @@ impl PyErrStateNormalized {
             ptype: pvalue.get_type().into(),
             #[cfg(not(Py_3_12))]
             ptraceback: unsafe {
-                Py::from_owned_ptr_or_opt(
-                    pvalue.py(),
-                    ffi::PyException_GetTraceback(pvalue.as_ptr()),
-                )
+                ffi::PyException_GetTraceback(pvalue.as_ptr())
+                    .assume_owned_or_opt(pvalue.py())
+                    // a traceback cleared with `PyException_SetTraceback` may be `None`
+                    .filter(|tb| !tb.is_none())
+                    .map(|tb| tb.downcast_into_unchecked().unbind())
             },
             pvalue: pvalue.into(),
         }
@@ -178,6 +179,8 @@ impl PyErrStateNormalized {
         unsafe {
             ffi::PyException_GetTraceback(self.pvalue.as_ptr())
                 .assume_owned_or_opt(py)
+                // a traceback cleared with `PyException_SetTraceback` may be `None`
+                .filter(|b| !b.is_none())
                 .map(|b| b.downcast_into_unchecked())
         }
     }
@@ -339,6 +339,43 @@ impl PyErr {
         self.normalized(py).ptraceback(py)
     }
 
+    /// Sets the traceback of this exception object, pass `None` to clear it.
+    ///
+    /// This sets the `__traceback__` attribute of the exception value, which is shown when the
+    /// exception is printed or raised in Python.
+    pub fn set_traceback(&mut self, py: Python<'_>, traceback: Option<Bound<'_, PyTraceback>>) {
+        let value = self.value(py).clone();
+        let traceback = traceback.map_or_else(|| py.None().into_bound(py), Bound::into_any);
+        unsafe {
+            // PyException_SetTraceback only fails if traceback is not a traceback or None
+            ffi::PyException_SetTraceback(value.as_ptr(), traceback.as_ptr());
+        }
+        // Before Python 3.12 the normalized state keeps its own copy of the traceback
+        *self = PyErr::from_value(value.into_any());
+    }
+
+    /// Returns this error with its traceback replaced by `traceback`.
+    ///
+    /// This is useful to report where an error originated when converting it from another error,
+    /// see [`PyErr::set_traceback`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use pyo3::{exceptions::PyRuntimeError, ffi::c_str, Python};
+    ///
+    /// Python::with_gil(|py| {
+    ///     let original = py.run(c_str!("1 / 0"), None, None).unwrap_err();
+    ///     let traceback = original.traceback(py).unwrap();
+    ///
+    ///     let err = PyRuntimeError::new_err("calculation failed").with_traceback(py, traceback);
+    ///     assert!(err.traceback(py).is_some());
+    /// });
+    /// ```
+    pub fn with_traceback(mut self, py: Python<'_>, traceback: Bound<'_, PyTraceback>) -> PyErr {
+        self.set_traceback(py, Some(traceback));
+        self
+    }
+
     /// Deprecated name for [`PyErr::traceback`].
     #[deprecated(since = "0.23.0", note = "renamed to `PyErr::traceback`")]
     #[inline]
@@ -375,6 +412,40 @@ impl PyErr {
         Some(PyErr::from_state(PyErrState::normalized(state)))
     }
 
+    /// Takes the current error from the Python interpreter's global state, clears the global
+    /// state and returns the normalized exception instance. If no error is set, returns `None`.
+    ///
+    /// This is a shorthand for `PyErr::take(py).map(|err| err.into_value(py).into_bound(py))`,
+    /// useful for inspecting the attributes of the live exception object. Like [`PyErr::take`],
+    /// this will resume the panic if the error is a `PanicException`.
+    pub fn occurred_fetch_normalized(py: Python<'_>) -> Option<Bound<'_, PyBaseException>> {
+        PyErr::take(py).map(|err| err.into_value(py).into_bound(py))
+    }
+
+    /// Simulates the arrival of a `SIGINT` signal, so that `KeyboardInterrupt` is raised by the
+    /// next signal check, e.g. [`Python::check_signals`].
+    ///
+    /// This is useful for testing interrupt handling and for cancelling long-running work. Like
+    /// all signal handling in Python, the interrupt is only acted upon in the main thread.
+    ///
+    /// This calls [`PyErr_SetInterruptEx`][1] on Python 3.10 and up and
+    /// [`PyErr_SetInterrupt`][2] on older versions. It does nothing if `SIGINT` is not handled
+    /// by Python, which is the case when PyO3 initialized the interpreter itself, as it does not
+    /// install Python's signal handlers.
+    ///
+    /// [1]: https://docs.python.org/3/c-api/exceptions.html#c.PyErr_SetInterruptEx
+    /// [2]: https://docs.python.org/3/c-api/exceptions.html#c.PyErr_SetInterrupt
+    pub fn set_interrupt(_py: Python<'_>) {
+        #[cfg(Py_3_10)]
+        unsafe {
+            ffi::PyErr_SetInterruptEx(libc::SIGINT)
+        }
+        #[cfg(not(Py_3_10))]
+        unsafe {
+            ffi::PyErr_SetInterrupt()
+        }
+    }
+
     fn print_panic_and_unwind(py: Python<'_>, state: PyErrState, msg: String) -> ! {
         eprintln!("--- PyO3 is resuming a panic after fetching a PanicException from Python. ---");
         eprintln!("Python stack trace below:");
@@ -412,6 +483,19 @@ impl PyErr {
         }
     }
 
+    /// Like [`PyErr::fetch`], but skips the check for a `PanicException`.
+    ///
+    /// That check may need to create the `PanicException` type object, which calls into Python.
+    /// This is for use when an error is known not to be a `PanicException` and calling into
+    /// Python would fail, e.g. when the recursion limit has been reached.
+    #[cfg(Py_3_9)]
+    pub(crate) fn fetch_no_panic_check(py: Python<'_>) -> PyErr {
+        match PyErrStateNormalized::take(py) {
+            Some(state) => PyErr::from_state(PyErrState::normalized(state)),
+            None => Self::fetch(py),
+        }
+    }
+
     /// Creates a new exception type with the given name and docstring.
     ///
     /// - `base` can be an existing exception type to subclass, or a tuple of classes.
@@ -497,6 +581,127 @@ impl PyErr {
         }
     }
 
+    /// Returns a one-line summary of the exception in the form `TypeName: message`, without
+    /// any traceback.
+    ///
+    /// If `str()` of the exception value is empty (e.g. because the exception was created
+    /// without arguments) only the type name is returned. If `str()` raises, the message is
+    /// replaced by `<exception str() failed>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::exceptions::PyValueError;
+    ///
+    /// Python::with_gil(|py| {
+    ///     let err = PyValueError::new_err("bad");
+    ///     assert_eq!(err.short_message(py), "ValueError: bad");
+    ///
+    ///     let err = PyValueError::new_err(());
+    ///     assert_eq!(err.short_message(py), "ValueError");
+    /// });
+    /// ```
+    pub fn short_message(&self, py: Python<'_>) -> String {
+        let value = self.value(py);
+        let mut message = match value.get_type().qualname() {
+            Ok(name) => name.to_string_lossy().into_owned(),
+            Err(_) => String::from("<unknown exception type>"),
+        };
+        match value.str() {
+            Ok(s) => {
+                let s = s.to_string_lossy();
+                if !s.is_empty() {
+                    message.push_str(": ");
+                    message.push_str(&s);
+                }
+            }
+            Err(_) => message.push_str(": <exception str() failed>"),
+        }
+        message
+    }
+
+    /// Formats this exception together with its chain of causes and contexts, like the traceback
+    /// Python prints for an uncaught exception.
+    ///
+    /// The chain is followed through `__cause__` (set by `raise ... from ...`) and `__context__`
+    /// (set when raising while handling another exception), unless `__suppress_context__` is set.
+    /// The oldest exception is formatted first, and each exception is written as its traceback
+    /// (if any) followed by its [`short_message`](PyErr::short_message). Each exception is shown
+    /// at most once, so cycles in the chain do not cause infinite output.
+    ///
+    /// Unlike [`PyErr::print`] this does not write to `sys.stderr`, which makes it suitable for
+    /// logging.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::exceptions::{PyRuntimeError, PyValueError};
+    ///
+    /// Python::with_gil(|py| {
+    ///     let err = PyRuntimeError::new_err("outer");
+    ///     err.set_cause(py, Some(PyValueError::new_err("inner")));
+    ///     assert_eq!(
+    ///         err.display_chain(py),
+    ///         "ValueError: inner\n\
+    ///          \n\
+    ///          The above exception was the direct cause of the following exception:\n\
+    ///          \n\
+    ///          RuntimeError: outer\n"
+    ///     );
+    /// });
+    /// ```
+    pub fn display_chain(&self, py: Python<'_>) -> String {
+        const CAUSE_MESSAGE: &str =
+            "The above exception was the direct cause of the following exception:";
+        const CONTEXT_MESSAGE: &str =
+            "During handling of the above exception, another exception occurred:";
+
+        // the chain from newest to oldest, each with the message linking it to the next newest
+        let mut chain: Vec<(PyErr, Option<&str>)> = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+        let mut current = Some((self.clone_ref(py), None));
+        while let Some((err, link)) = current.take() {
+            if !seen.insert(err.value(py).as_ptr()) {
+                break;
+            }
+            let value = err.value(py);
+            current = if let Some(cause) = err.cause(py) {
+                Some((cause, Some(CAUSE_MESSAGE)))
+            } else if value
+                .getattr(crate::intern!(py, "__suppress_context__"))
+                .and_then(|suppress| suppress.is_truthy())
+                .unwrap_or(false)
+            {
+                None
+            } else {
+                use crate::ffi_ptr_ext::FfiPtrExt;
+                unsafe { ffi::PyException_GetContext(value.as_ptr()).assume_owned_or_opt(py) }
+                    .filter(|context| !context.is_none())
+                    .map(|context| (PyErr::from_value(context), Some(CONTEXT_MESSAGE)))
+            };
+            chain.push((err, link));
+        }
+
+        let mut output = String::new();
+        for (err, link) in chain.iter().rev() {
+            if let Some(traceback) = err.traceback(py) {
+                if let Ok(formatted) = traceback.format() {
+                    output.push_str(&formatted);
+                }
+            }
+            output.push_str(&err.short_message(py));
+            output.push('\n');
+            if let Some(link) = link {
+                output.push('\n');
+                output.push_str(link);
+                output.push_str("\n\n");
+            }
+        }
+        output
+    }
+
     /// Calls `sys.excepthook` and then prints a standard traceback to `sys.stderr`.
     pub fn print(&self, py: Python<'_>) {
         self.clone_ref(py).restore(py);
@@ -1008,6 +1213,22 @@ mod tests {
         })
     }
 
+    #[test]
+    fn occurred_fetch_normalized() {
+        use crate::types::any::PyAnyMethods;
+
+        Python::with_gil(|py| {
+            assert!(PyErr::occurred_fetch_normalized(py).is_none());
+
+            unsafe { ffi::PyErr_SetString(ffi::PyExc_ValueError, ffi::c_str!("boom").as_ptr()) };
+            let value = PyErr::occurred_fetch_normalized(py).unwrap();
+            assert!(!PyErr::occurred(py));
+            assert!(value.is_instance_of::<PyValueError>());
+            let args: (String,) = value.getattr("args").unwrap().extract().unwrap();
+            assert_eq!(args.0, "boom");
+        })
+    }
+
     #[test]
     fn invalid_error_type() {
         Python::with_gil(|py| {
@@ -1111,6 +1332,29 @@ mod tests {
         });
     }
 
+    #[test]
+    fn err_short_message() {
+        Python::with_gil(|py| {
+            let err = PyValueError::new_err("bad");
+            assert_eq!(err.short_message(py), "ValueError: bad");
+
+            let err = PyTypeError::new_err(());
+            assert_eq!(err.short_message(py), "TypeError");
+
+            let err = py
+                .run(
+                    ffi::c_str!(
+                        "class BadStr(Exception):\n    def __str__(self):\n        raise RuntimeError()\nraise BadStr()"
+                    ),
+                    None,
+                    None,
+                )
+                .expect_err("raising should have given us an error");
+            assert_eq!(err.short_message(py), "BadStr: <exception str() failed>");
+            assert!(!PyErr::occurred(py));
+        });
+    }
+
     #[test]
     fn test_pyerr_send_sync() {
         fn is_send<T: Send>() {}
@@ -1144,6 +1388,115 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_display_chain() {
+        Python::with_gil(|py| {
+            let err = py
+                .run(
+                    ffi::c_str!(
+                        r#"
+try:
+    try:
+        raise ValueError("first")
+    except ValueError as e:
+        raise KeyError("second") from e
+except KeyError:
+    raise RuntimeError("third")
+"#
+                    ),
+                    None,
+                    None,
+                )
+                .unwrap_err();
+            let chain = err.display_chain(py);
+            let lines: Vec<_> = chain
+                .lines()
+                .filter(|line| !line.starts_with(' ') && !line.is_empty())
+                .collect();
+            assert_eq!(
+                lines,
+                [
+                    "Traceback (most recent call last):",
+                    "ValueError: first",
+                    "The above exception was the direct cause of the following exception:",
+                    "Traceback (most recent call last):",
+                    "KeyError: 'second'",
+                    "During handling of the above exception, another exception occurred:",
+                    "Traceback (most recent call last):",
+                    "RuntimeError: third",
+                ]
+            );
+
+            // `raise ... from None` suppresses the context
+            let err = py
+                .run(
+                    ffi::c_str!(
+                        r#"
+try:
+    raise ValueError("hidden")
+except ValueError:
+    raise RuntimeError("shown") from None
+"#
+                    ),
+                    None,
+                    None,
+                )
+                .unwrap_err();
+            let chain = err.display_chain(py);
+            assert!(!chain.contains("hidden"));
+            assert!(chain.ends_with("RuntimeError: shown\n"));
+
+            // cycles are only followed once
+            let a = PyValueError::new_err("a");
+            let b = PyTypeError::new_err("b");
+            a.set_cause(py, Some(b.clone_ref(py)));
+            b.set_cause(py, Some(a.clone_ref(py)));
+            assert_eq!(
+                a.display_chain(py),
+                "TypeError: b\n\
+                 \n\
+                 The above exception was the direct cause of the following exception:\n\
+                 \n\
+                 ValueError: a\n"
+            );
+        });
+    }
+
+    #[test]
+    fn test_pyerr_set_traceback() {
+        use crate::types::PyAnyMethods;
+
+        Python::with_gil(|py| {
+            let original = py
+                .run(ffi::c_str!("1 / 0"), None, None)
+                .expect_err("raising should have given us an error");
+            let traceback = original.traceback(py).unwrap();
+
+            let err = exceptions::PyRuntimeError::new_err("banana");
+            assert!(err.traceback(py).is_none());
+
+            let mut err = err.with_traceback(py, traceback.clone());
+            assert!(err.traceback(py).unwrap().is(&traceback));
+            assert!(err
+                .value(py)
+                .getattr("__traceback__")
+                .unwrap()
+                .is(&traceback));
+            assert!(err.clone_ref(py).traceback(py).unwrap().is(&traceback));
+            assert!(err
+                .clone_ref(py)
+                .into_value(py)
+                .bind(py)
+                .getattr("__traceback__")
+                .unwrap()
+                .is(&traceback));
+
+            err.set_traceback(py, None);
+            assert!(err.traceback(py).is_none());
+            assert!(err.value(py).getattr("__traceback__").unwrap().is_none());
+        });
+    }
+
     #[test]
     fn test_pyerr_cause() {
         Python::with_gil(|py| {
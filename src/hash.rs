@@ -0,0 +1,83 @@
+//! Helpers for implementing Python's hashing protocol.
+
+use crate::ffi::Py_hash_t;
+
+/// Reduces a 64-bit Rust hash to a valid Python hash value.
+///
+/// On platforms where [`Py_hash_t`] is narrower than 64 bits, the upper and lower halves of `h`
+/// are XOR-folded together so that no bits of the input are discarded. Because `-1` is reserved
+/// by the Python C API to signal an error from `__hash__`, a value which would fold to `-1` is
+/// mapped to `-2`, matching what CPython does for its own hash functions.
+///
+/// This is useful when implementing `__hash__` by hand, for example by feeding the fields of a
+/// struct into a [`Hasher`](std::hash::Hasher).
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::hash::fold_to_py_hash;
+/// use pyo3::prelude::*;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+///
+/// #[pyclass(frozen)]
+/// struct Point {
+///     x: i64,
+///     y: i64,
+/// }
+///
+/// #[pymethods]
+/// impl Point {
+///     fn __hash__(&self) -> isize {
+///         let mut hasher = DefaultHasher::new();
+///         (self.x, self.y).hash(&mut hasher);
+///         fold_to_py_hash(hasher.finish())
+///     }
+/// }
+///
+/// assert_eq!(fold_to_py_hash(u64::MAX), -2);
+/// ```
+#[inline]
+pub const fn fold_to_py_hash(h: u64) -> Py_hash_t {
+    #[cfg(target_pointer_width = "64")]
+    let folded = h as Py_hash_t;
+    #[cfg(not(target_pointer_width = "64"))]
+    let folded = (h ^ (h >> 32)) as u32 as Py_hash_t;
+
+    if folded == -1 {
+        -2
+    } else {
+        folded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fold_to_py_hash;
+
+    #[test]
+    fn test_fold_to_py_hash() {
+        assert_eq!(fold_to_py_hash(0), 0);
+        assert_eq!(fold_to_py_hash(1), 1);
+        assert_eq!(fold_to_py_hash(12345), 12345);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_fold_to_py_hash_64bit() {
+        assert_eq!(fold_to_py_hash(u64::MAX), -2);
+        assert_eq!(fold_to_py_hash(u64::MAX - 1), -2);
+        assert_eq!(fold_to_py_hash(u64::MAX - 2), -3);
+        assert_eq!(fold_to_py_hash(1 << 63), isize::MIN);
+        assert_eq!(fold_to_py_hash(u32::MAX.into()), 0xFFFF_FFFF);
+    }
+
+    #[test]
+    #[cfg(not(target_pointer_width = "64"))]
+    fn test_fold_to_py_hash_32bit() {
+        assert_eq!(fold_to_py_hash(u32::MAX.into()), -2);
+        assert_eq!(fold_to_py_hash(u64::MAX), 0);
+        assert_eq!(fold_to_py_hash(1 << 32), 1);
+        assert_eq!(fold_to_py_hash(0xFFFF_0000_0000_FFFF), -2);
+    }
+}
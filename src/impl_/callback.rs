@@ -29,6 +29,9 @@ pub trait IntoPyCallbackOutput<'py, Target> {
     fn convert(self, py: Python<'py>) -> PyResult<Target>;
 }
 
+// Note: `Result<T, E>` deliberately does not implement `IntoPyObject`. Such an impl would overlap
+// with this one through the blanket `T: IntoPyObject` impls below, and would make the return value
+// conversion in `impl_::wrap` ambiguous for every `#[pyfunction]` returning a `PyResult`.
 impl<'py, T, E, U> IntoPyCallbackOutput<'py, U> for Result<T, E>
 where
     T: IntoPyCallbackOutput<'py, U>,
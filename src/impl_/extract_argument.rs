@@ -131,7 +131,7 @@ pub fn extract_optional_argument<'a, 'py, T>(
     obj: Option<&'a Bound<'py, PyAny>>,
     holder: &'a mut T::Holder,
     arg_name: &str,
-    default: fn() -> Option<T>,
+    default: impl FnOnce() -> Option<T>,
 ) -> PyResult<Option<T>>
 where
     T: PyFunctionArgument<'a, 'py>,
@@ -155,7 +155,7 @@ pub fn extract_argument_with_default<'a, 'py, T>(
     obj: Option<&'a Bound<'py, PyAny>>,
     holder: &'a mut T::Holder,
     arg_name: &str,
-    default: fn() -> T,
+    default: impl FnOnce() -> T,
 ) -> PyResult<T>
 where
     T: PyFunctionArgument<'a, 'py>,
@@ -185,7 +185,7 @@ pub fn from_py_with_with_default<'a, 'py, T>(
     obj: Option<&'a Bound<'py, PyAny>>,
     arg_name: &str,
     extractor: fn(&'a Bound<'py, PyAny>) -> PyResult<T>,
-    default: fn() -> T,
+    default: impl FnOnce() -> T,
 ) -> PyResult<T> {
     match obj {
         Some(obj) => from_py_with(obj, arg_name, extractor),
@@ -193,6 +193,50 @@ pub fn from_py_with_with_default<'a, 'py, T>(
     }
 }
 
+/// Alternative to [`extract_argument`] used when `*args` has a `#[pyo3(from_py_with)]` annotation,
+/// which applies the extractor to each positional argument.
+#[doc(hidden)]
+pub fn from_py_with_varargs<'py, T, C>(
+    args: &Bound<'py, PyAny>,
+    arg_name: &str,
+    extractor: fn(&Bound<'py, PyAny>) -> PyResult<T>,
+) -> PyResult<C>
+where
+    C: FromIterator<T>,
+{
+    // Safety: the varargs passed by the generated code are always a tuple
+    let args = unsafe { args.downcast_unchecked::<PyTuple>() };
+    args.iter()
+        .map(|arg| extractor(&arg))
+        .collect::<PyResult<C>>()
+        .map_err(|e| argument_extraction_error(args.py(), arg_name, e))
+}
+
+/// Alternative to [`extract_argument`] used when `**kwargs` has a `#[pyo3(from_py_with)]`
+/// annotation, which applies the extractor to each keyword argument's value.
+#[doc(hidden)]
+pub fn from_py_with_varkeywords<'py, K, T, C>(
+    kwargs: Option<&Bound<'py, PyAny>>,
+    arg_name: &str,
+    extractor: fn(&Bound<'py, PyAny>) -> PyResult<T>,
+) -> PyResult<Option<C>>
+where
+    K: for<'a> FromPyObjectBound<'a, 'py>,
+    C: FromIterator<(K, T)>,
+{
+    let kwargs = match kwargs {
+        // Safety: the varkeywords passed by the generated code are always a dict
+        Some(kwargs) => unsafe { kwargs.downcast_unchecked::<PyDict>() },
+        None => return Ok(None),
+    };
+    kwargs
+        .iter()
+        .map(|(key, value)| Ok((key.extract()?, extractor(&value)?)))
+        .collect::<PyResult<C>>()
+        .map(Some)
+        .map_err(|e| argument_extraction_error(kwargs.py(), arg_name, e))
+}
+
 /// Adds the argument name to the error message of an error which occurred during argument extraction.
 ///
 /// Only modifies TypeError. (Cannot guarantee all exceptions have constructors from
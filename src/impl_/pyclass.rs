@@ -1,5 +1,7 @@
 use crate::{
-    exceptions::{PyAttributeError, PyNotImplementedError, PyRuntimeError, PyValueError},
+    exceptions::{
+        PyAttributeError, PyNotImplementedError, PyRuntimeError, PyRuntimeWarning, PyValueError,
+    },
     ffi,
     impl_::{
         freelist::PyObjectFreeList,
@@ -10,7 +12,7 @@ use crate::{
     pycell::PyBorrowError,
     types::{any::PyAnyMethods, PyBool},
     Borrowed, BoundObject, IntoPyObject, IntoPyObjectExt, Py, PyAny, PyClass, PyErr, PyRef,
-    PyResult, PyTypeInfo, Python,
+    PyResult, PyTraverseError, PyTypeInfo, PyVisit, Python,
 };
 #[allow(deprecated)]
 use crate::{IntoPy, ToPyObject};
@@ -20,7 +22,10 @@ use std::{
     marker::PhantomData,
     os::raw::{c_int, c_void},
     ptr::NonNull,
-    sync::Mutex,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
     thread,
 };
 
@@ -44,6 +49,20 @@ pub fn weaklist_offset<T: PyClass>() -> ffi::Py_ssize_t {
     PyClassObject::<T>::weaklist_offset()
 }
 
+/// Used by the generated `__getbuffer__` slot to refuse requests for a writable buffer from
+/// a frozen class, whose contents can never be mutated.
+#[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+pub fn check_buffer_writable<T: PyClass>(flags: c_int) -> PyResult<()> {
+    use crate::pyclass::boolean_struct::private::Boolean;
+    if T::Frozen::VALUE && flags & ffi::PyBUF_WRITABLE == ffi::PyBUF_WRITABLE {
+        return Err(crate::exceptions::PyBufferError::new_err(format!(
+            "cannot export a writable buffer from frozen class '{}'",
+            T::NAME
+        )));
+    }
+    Ok(())
+}
+
 mod sealed {
     pub trait Sealed {}
 
@@ -51,6 +70,7 @@ mod sealed {
     impl Sealed for super::PyClassDictSlot {}
     impl Sealed for super::PyClassWeakRefSlot {}
     impl Sealed for super::ThreadCheckerImpl {}
+    impl Sealed for super::LeakingThreadCheckerImpl {}
     impl<T: Send> Sealed for super::SendablePyClass<T> {}
 }
 
@@ -160,6 +180,19 @@ pub struct PyClassItems {
 // Allow PyClassItems in statics
 unsafe impl Sync for PyClassItems {}
 
+/// The renaming rules supported by `#[pyclass(rename_all = "...")]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenamingRule {
+    CamelCase,
+    KebabCase,
+    Lowercase,
+    PascalCase,
+    ScreamingKebabCase,
+    ScreamingSnakeCase,
+    SnakeCase,
+    Uppercase,
+}
+
 /// Implements the underlying functionality of `#[pyclass]`, assembled by various proc macros.
 ///
 /// Users are discouraged from implementing this trait manually; it is a PyO3 implementation detail
@@ -177,6 +210,10 @@ pub trait PyClassImpl: Sized + 'static {
     /// #[pyclass(sequence)]
     const IS_SEQUENCE: bool = false;
 
+    /// #[pyclass(rename_all = "...")], also applied to `#[getter]`s and `#[setter]`s without an
+    /// explicit name.
+    const RENAMING_RULE: Option<RenamingRule> = None;
+
     /// Base class
     type BaseType: PyTypeInfo + PyClassBaseType;
 
@@ -1122,6 +1159,93 @@ impl<T> PyClassThreadChecker<T> for ThreadCheckerImpl {
     }
 }
 
+/// Thread checker for `#[pyclass(unsendable, leak_on_foreign_drop)]` types.
+/// Panics when the value is accessed by another thread, like [`ThreadCheckerImpl`], but
+/// silently leaks the value when it is dropped on another thread, emitting a single
+/// `RuntimeWarning` per process the first time this happens.
+#[doc(hidden)]
+pub struct LeakingThreadCheckerImpl(ThreadCheckerImpl);
+
+impl LeakingThreadCheckerImpl {
+    fn can_drop(&self, py: Python<'_>, type_name: &'static str) -> bool {
+        static WARNED: AtomicBool = AtomicBool::new(false);
+
+        if !self.0.check() {
+            if !WARNED.swap(true, Ordering::Relaxed) {
+                let message = CString::new(format!(
+                    "{} is unsendable, but is being dropped on another thread; leaking it \
+                     (further leaks of unsendable objects will not be reported)",
+                    type_name
+                ))
+                .unwrap_or_default();
+                if let Err(err) = PyErr::warn(py, &py.get_type::<PyRuntimeWarning>(), &message, 0) {
+                    err.write_unraisable(py, None);
+                }
+            }
+            return false;
+        }
+
+        true
+    }
+}
+
+impl<T> PyClassThreadChecker<T> for LeakingThreadCheckerImpl {
+    fn ensure(&self) {
+        self.0.ensure(std::any::type_name::<T>());
+    }
+    fn check(&self) -> bool {
+        self.0.check()
+    }
+    fn can_drop(&self, py: Python<'_>) -> bool {
+        self.can_drop(py, std::any::type_name::<T>())
+    }
+    fn new() -> Self {
+        LeakingThreadCheckerImpl(ThreadCheckerImpl(thread::current().id()))
+    }
+}
+
+/// Visits and clears the Python references held by a field annotated with `#[pyo3(traverse)]`
+/// in a `#[pyclass(gc)]` type.
+#[doc(hidden)]
+pub trait TraverseField {
+    fn traverse_field(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError>;
+    fn clear_field(&mut self);
+}
+
+impl<T> TraverseField for Py<T> {
+    fn traverse_field(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(self)
+    }
+
+    // Only reached for elements of `Vec`s, which are released by clearing the `Vec`; the
+    // `#[pyclass(gc)]` macro rejects bare `Py<T>` fields.
+    fn clear_field(&mut self) {}
+}
+
+impl<T: TraverseField> TraverseField for Option<T> {
+    fn traverse_field(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Some(value) => value.traverse_field(visit),
+            None => Ok(()),
+        }
+    }
+
+    fn clear_field(&mut self) {
+        *self = None;
+    }
+}
+
+impl<T: TraverseField> TraverseField for Vec<T> {
+    fn traverse_field(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.iter()
+            .try_for_each(|value| value.traverse_field(visit))
+    }
+
+    fn clear_field(&mut self) {
+        self.clear();
+    }
+}
+
 /// Trait denoting that this class is suitable to be used as a base type for PyClass.
 
 #[cfg_attr(
@@ -2,13 +2,13 @@ use crate::conversion::IntoPyObject;
 use crate::err::{self, PyErr, PyResult};
 use crate::impl_::pycell::PyClassObject;
 use crate::internal_tricks::ptr_from_ref;
-use crate::pycell::{PyBorrowError, PyBorrowMutError};
+use crate::pycell::{impl_::PyClassBorrowChecker, BorrowState, PyBorrowError, PyBorrowMutError};
 use crate::pyclass::boolean_struct::{False, True};
 use crate::types::{any::PyAnyMethods, string::PyStringMethods, typeobject::PyTypeMethods};
 use crate::types::{DerefToPyAny, PyDict, PyString, PyTuple};
 use crate::{
-    ffi, AsPyPointer, DowncastError, FromPyObject, PyAny, PyClass, PyClassInitializer, PyRef,
-    PyRefMut, PyTypeInfo, Python,
+    ffi, AsPyPointer, DowncastError, DowncastIntoError, FromPyObject, PyAny, PyClass,
+    PyClassInitializer, PyRef, PyRefMut, PyTypeInfo, Python,
 };
 use crate::{gil, PyTypeCheck};
 #[allow(deprecated)]
@@ -16,6 +16,7 @@ use crate::{IntoPy, ToPyObject};
 use std::marker::PhantomData;
 use std::mem::ManuallyDrop;
 use std::ops::Deref;
+use std::os::raw::c_int;
 use std::ptr::NonNull;
 
 /// Owned or borrowed gil-bound Python smart pointer
@@ -326,6 +327,43 @@ where
         PyRefMut::try_borrow(self)
     }
 
+    /// Returns the current borrow state of the value `T`, without taking a borrow.
+    ///
+    /// This is intended for diagnostics, e.g. to find out why a call to [`try_borrow`](#method.try_borrow)
+    /// or [`try_borrow_mut`](#method.try_borrow_mut) failed, or to assert in tests that no
+    /// [`PyRef`] or [`PyRefMut`] guards have been leaked. The returned state is a snapshot
+    /// which may be outdated as soon as other borrows are taken or released.
+    ///
+    /// Frozen classes without a mutable ancestor do not track borrows, so this always returns
+    /// [`BorrowState::Unused`] for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pyo3::prelude::*;
+    /// use pyo3::pycell::BorrowState;
+    ///
+    /// #[pyclass]
+    /// struct Foo {}
+    ///
+    /// Python::with_gil(|py| {
+    ///     let foo = Bound::new(py, Foo {}).unwrap();
+    ///     assert_eq!(foo.borrow_count(), BorrowState::Unused);
+    ///
+    ///     let first = foo.borrow();
+    ///     let second = foo.borrow();
+    ///     assert_eq!(foo.borrow_count(), BorrowState::Shared(2));
+    ///     drop((first, second));
+    ///
+    ///     let _exclusive = foo.borrow_mut();
+    ///     assert_eq!(foo.borrow_count(), BorrowState::Exclusive);
+    /// });
+    /// ```
+    #[inline]
+    pub fn borrow_count(&self) -> BorrowState {
+        self.get_class_object().borrow_checker().borrow_state()
+    }
+
     /// Provide an immutable borrow of the value `T` without acquiring the GIL.
     ///
     /// This is available if the class is [`frozen`][macro@crate::pyclass] and [`Sync`].
@@ -1332,6 +1370,55 @@ impl<T> Py<T> {
         self.as_ptr() == o.as_ptr()
     }
 
+    /// Tests whether `self` is equal to `other`, without binding either handle.
+    ///
+    /// This is equivalent to the Python expression `self == other`. Like Python's containers,
+    /// this assumes that every object is equal to itself, so handles pointing to the same object
+    /// compare equal without calling `__eq__`. Errors raised by `__eq__` are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    ///
+    /// Python::with_gil(|py| -> PyResult<()> {
+    ///     let a: Py<PyAny> = 1i32.into_pyobject(py)?.into_any().unbind();
+    ///     let b: Py<PyAny> = 1.0f64.into_pyobject(py)?.into_any().unbind();
+    ///     assert!(a.eq(py, &b)?);
+    ///     Ok(())
+    /// })
+    /// # .unwrap();
+    /// ```
+    pub fn eq<U>(&self, py: Python<'_>, other: &Py<U>) -> PyResult<bool> {
+        if self.is(other) {
+            return Ok(true);
+        }
+        self.rich_compare_bool(py, other, ffi::Py_EQ)
+    }
+
+    /// Tests whether `self` is not equal to `other`, without binding either handle.
+    ///
+    /// This is equivalent to the Python expression `self != other`. Handles pointing to the same
+    /// object compare equal without calling `__ne__`, see [`Py::eq`].
+    pub fn ne<U>(&self, py: Python<'_>, other: &Py<U>) -> PyResult<bool> {
+        if self.is(other) {
+            return Ok(false);
+        }
+        self.rich_compare_bool(py, other, ffi::Py_NE)
+    }
+
+    fn rich_compare_bool<U>(
+        &self,
+        py: Python<'_>,
+        other: &Py<U>,
+        compare_op: c_int,
+    ) -> PyResult<bool> {
+        let result =
+            unsafe { ffi::PyObject_RichCompareBool(self.as_ptr(), other.as_ptr(), compare_op) };
+        err::error_on_minusone(py, result)?;
+        Ok(result == 1)
+    }
+
     /// Gets the reference count of the `ffi::PyObject` pointer.
     #[inline]
     pub fn get_refcnt(&self, _py: Python<'_>) -> isize {
@@ -1987,6 +2074,67 @@ impl PyObject {
     pub unsafe fn downcast_bound_unchecked<'py, T>(&self, py: Python<'py>) -> &Bound<'py, T> {
         self.bind(py).downcast_unchecked()
     }
+
+    /// Downcast this `PyObject` to a concrete Python type, returning a reference to a typed
+    /// `Py<T>` without any reference count changes.
+    ///
+    /// This is useful when storing untyped handles which occasionally need to be used as a
+    /// specific type, e.g. to be stored again as a typed `Py<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::{PyDict, PyList};
+    ///
+    /// Python::with_gil(|py| {
+    ///     let any: PyObject = PyDict::new(py).into();
+    ///
+    ///     let dict: &Py<PyDict> = any.downcast(py).unwrap();
+    ///     assert!(dict.is(&any));
+    ///     assert!(any.downcast::<PyList>(py).is_err());
+    /// });
+    /// ```
+    #[inline]
+    pub fn downcast<'a, 'py, T>(
+        &'a self,
+        py: Python<'py>,
+    ) -> Result<&'a Py<T>, DowncastError<'a, 'py>>
+    where
+        'py: 'a,
+        T: PyTypeCheck,
+    {
+        self.bind(py).downcast::<T>().map(Bound::as_unbound)
+    }
+
+    /// Like [`downcast`](Py::downcast) but takes ownership of `self`.
+    ///
+    /// In case of an error, it is possible to retrieve `self` again via
+    /// [`DowncastIntoError::into_inner`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::{PyDict, PyList};
+    ///
+    /// Python::with_gil(|py| {
+    ///     let any: PyObject = PyDict::new(py).into();
+    ///
+    ///     let dict: Py<PyDict> = any.downcast_into(py).unwrap();
+    ///     let any = dict.into_any();
+    ///
+    ///     let err = any.downcast_into::<PyList>(py).unwrap_err();
+    ///     let any: PyObject = err.into_inner().unbind();
+    /// });
+    /// ```
+    #[inline]
+    pub fn downcast_into<T>(self, py: Python<'_>) -> Result<Py<T>, DowncastIntoError<'_>>
+    where
+        T: PyTypeCheck,
+    {
+        self.into_bound(py).downcast_into::<T>().map(Bound::unbind)
+    }
 }
 
 #[cfg(test)]
@@ -2168,6 +2316,76 @@ a = A()
         })
     }
 
+    #[test]
+    fn test_py_downcast() {
+        use crate::types::{PyList, PyType};
+
+        Python::with_gil(|py| {
+            let any: PyObject = py.get_type::<PyDict>().into_any().unbind();
+            let refcnt = any.get_refcnt(py);
+
+            let ty: &Py<PyType> = any.downcast(py).unwrap();
+            assert!(ty.is(&any));
+            assert_eq!(any.get_refcnt(py), refcnt);
+
+            let err = any.downcast::<PyList>(py).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "'type' object cannot be converted to 'PyList'"
+            );
+
+            let ty: Py<PyType> = any.clone_ref(py).downcast_into(py).unwrap();
+            assert!(ty.is(&any));
+
+            let err = ty.into_any().downcast_into::<PyList>(py).unwrap_err();
+            assert!(err.into_inner().is(&any));
+        });
+    }
+
+    #[test]
+    fn test_py_eq_ne() {
+        use crate::exceptions::PyValueError;
+        use crate::types::PyModule;
+
+        Python::with_gil(|py| {
+            let one: PyObject = 1i32.into_pyobject(py).unwrap().into_any().unbind();
+            let one_float: PyObject = 1.0f64.into_pyobject(py).unwrap().into_any().unbind();
+            let two: PyObject = 2i32.into_pyobject(py).unwrap().into_any().unbind();
+            assert!(one.eq(py, &one_float).unwrap());
+            assert!(!one.ne(py, &one_float).unwrap());
+            assert!(!one.eq(py, &two).unwrap());
+            assert!(one.ne(py, &two).unwrap());
+
+            let module = PyModule::from_code(
+                py,
+                c_str!(
+                    r#"
+class RaisingEq:
+    def __eq__(self, other):
+        raise ValueError("no comparisons")
+
+    __ne__ = __eq__
+
+a = RaisingEq()
+b = RaisingEq()
+"#
+                ),
+                c_str!(""),
+                &generate_unique_module_name(""),
+            )
+            .unwrap();
+            let a: PyObject = module.getattr("a").unwrap().unbind();
+            let b: PyObject = module.getattr("b").unwrap().unbind();
+
+            // the same object compares equal without calling `__eq__`
+            assert!(a.eq(py, &a.clone_ref(py)).unwrap());
+            assert!(!a.ne(py, &a).unwrap());
+
+            assert!(a.eq(py, &b).unwrap_err().is_instance_of::<PyValueError>(py));
+            assert!(a.ne(py, &b).unwrap_err().is_instance_of::<PyValueError>(py));
+        })
+    }
+
     #[test]
     fn test_py2_from_py_object() {
         Python::with_gil(|py| {
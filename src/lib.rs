@@ -439,6 +439,7 @@ mod err;
 pub mod exceptions;
 pub mod ffi;
 mod gil;
+pub mod hash;
 #[doc(hidden)]
 pub mod impl_;
 mod instance;
@@ -119,21 +119,23 @@
 use crate::conversion::IntoPyObject;
 use crate::err::PyErr;
 use crate::err::{self, PyResult};
+use crate::exceptions::{PyBaseException, PyNameError};
 use crate::ffi_ptr_ext::FfiPtrExt;
 use crate::gil::{GILGuard, SuspendGIL};
 use crate::impl_::not_send::NotSend;
-use crate::py_result_ext::PyResultExt;
 use crate::types::any::PyAnyMethods;
 use crate::types::{
-    PyAny, PyDict, PyEllipsis, PyModule, PyNone, PyNotImplemented, PyString, PyType,
+    PyAny, PyCFunction, PyDict, PyDictMethods, PyEllipsis, PyList, PyListMethods, PyModule,
+    PyModuleMethods, PyNone, PyNotImplemented, PyString, PyTraceback, PyType,
 };
 use crate::version::PythonVersionInfo;
 #[allow(deprecated)]
 use crate::IntoPy;
-use crate::{ffi, Bound, Py, PyObject, PyTypeInfo};
+use crate::{ffi, intern, Bound, Py, PyObject, PyTypeInfo};
 use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
 use std::os::raw::c_int;
+use std::path::Path;
 
 /// Types that are safe to access while the GIL is not held.
 ///
@@ -411,6 +413,62 @@ impl Python<'_> {
         f(guard.python())
     }
 
+    /// Acquires the global interpreter lock like [`Python::with_gil`], and unbinds the object
+    /// returned by `f` so that it can be used outside of the closure.
+    ///
+    /// A [`Bound<'py, T>`](crate::Bound) cannot escape the `with_gil` closure because of its
+    /// lifetime. This returns the object as a [`Py<T>`], which can be stored and sent to other
+    /// threads, and bound again later with [`Py::bind`].
+    ///
+    /// For closures which may fail, use [`Python::try_with_gil_detached`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyList;
+    ///
+    /// let list: Py<PyList> = Python::with_gil_detached(|py| PyList::new(py, [1, 2, 3]).unwrap());
+    ///
+    /// Python::with_gil(|py| assert_eq!(list.bind(py).len(), 3));
+    /// ```
+    #[inline]
+    pub fn with_gil_detached<F, T>(f: F) -> Py<T>
+    where
+        F: for<'py> FnOnce(Python<'py>) -> Bound<'py, T>,
+    {
+        Python::with_gil(|py| f(py).unbind())
+    }
+
+    /// Like [`Python::with_gil_detached`], but for closures returning a [`PyResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyDict;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// let dict: Py<PyDict> = Python::try_with_gil_detached(|py| {
+    ///     let dict = PyDict::new(py);
+    ///     dict.set_item("answer", 42)?;
+    ///     Ok(dict)
+    /// })?;
+    ///
+    /// Python::with_gil(|py| -> PyResult<()> {
+    ///     assert_eq!(dict.bind(py).get_item("answer")?.unwrap().extract::<i32>()?, 42);
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_with_gil_detached<F, T>(f: F) -> PyResult<Py<T>>
+    where
+        F: for<'py> FnOnce(Python<'py>) -> PyResult<Bound<'py, T>>,
+    {
+        Python::with_gil(|py| f(py).map(Bound::unbind))
+    }
+
     /// Like [`Python::with_gil`] except Python interpreter state checking is skipped.
     ///
     /// Normally when the GIL is acquired, we check that the Python interpreter is an
@@ -434,6 +492,10 @@ impl Python<'_> {
     /// is called because the interpreter is only partially initialized.
     ///
     /// Behavior in other scenarios is not documented.
+    ///
+    /// This function is not needed to avoid overhead in FFI callbacks which are known to run with
+    /// the GIL held: if the GIL is already held, no locking happens, and code which cannot afford
+    /// even the bookkeeping can obtain a token with [`Python::assume_gil_acquired`].
     #[inline]
     pub unsafe fn with_gil_unchecked<F, R>(f: F) -> R
     where
@@ -548,6 +610,142 @@ impl<'py> Python<'py> {
         self.run_code(code, ffi::Py_eval_input, globals, locals)
     }
 
+    /// Evaluates a Python expression with only the builtins named in `allowed_names` available.
+    ///
+    /// The expression runs against fresh globals whose `__builtins__` is a new dictionary holding
+    /// just the allowed builtins, so using any other builtin raises `NameError`. Names in
+    /// `allowed_names` which are not builtins are ignored.
+    ///
+    /// This is a convenience for evaluating simple user-provided expressions, **not** a security
+    /// boundary: Python offers many ways to reach arbitrary objects (for example through the
+    /// attributes of any value), so untrusted code must never be evaluated with this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pyo3::prelude::*;
+    /// # use pyo3::ffi::c_str;
+    /// # Python::with_gil(|py| {
+    /// let result = py.eval_restricted(c_str!("len('abc')"), &["len"]).unwrap();
+    /// assert_eq!(result.extract::<usize>().unwrap(), 3);
+    ///
+    /// let err = py.eval_restricted(c_str!("open('data.txt')"), &["len"]).unwrap_err();
+    /// assert!(err.is_instance_of::<pyo3::exceptions::PyNameError>(py));
+    /// # });
+    /// ```
+    pub fn eval_restricted(
+        self,
+        code: &CStr,
+        allowed_names: &[&str],
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let builtins = PyModule::import(self, "builtins")?.dict();
+        let restricted = PyDict::new(self);
+        for &name in allowed_names {
+            if let Some(value) = builtins.get_item(name)? {
+                restricted.set_item(name, value)?;
+            }
+        }
+        let globals = PyDict::new(self);
+        globals.set_item(intern!(self, "__builtins__"), restricted)?;
+        self.eval(code, Some(&globals), None)
+    }
+
+    /// Installs `hook` as `sys.excepthook`, the function Python calls with an uncaught exception.
+    ///
+    /// The hook receives the exception type, value and traceback whenever an exception propagates
+    /// to the top level, e.g. when it is reported by [`PyErr::print`] or escapes a Python thread's
+    /// main code. This allows uncaught Python errors to be routed into a Rust logging system.
+    ///
+    /// If `hook` returns an error, that error is reported with the interpreter's default hook
+    /// (`sys.__excepthook__`) instead of being raised.
+    ///
+    /// Returns the hook which was installed before, so that it can be put back with
+    /// [`Python::reset_excepthook`]. This keeps hooks installed by other libraries intact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pyo3::prelude::*;
+    /// # use pyo3::ffi::c_str;
+    /// # Python::with_gil(|py| -> PyResult<()> {
+    /// let previous = py.set_excepthook(|ty, value, _traceback| {
+    ///     eprintln!("uncaught {}: {}", ty.name()?, value);
+    ///     Ok(())
+    /// })?;
+    ///
+    /// let err = py.run(c_str!("raise ValueError('oops')"), None, None).unwrap_err();
+    /// err.print(py); // calls the hook above
+    ///
+    /// py.reset_excepthook(&previous)?;
+    /// # Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub fn set_excepthook<F>(self, hook: F) -> PyResult<Bound<'py, PyAny>>
+    where
+        F: for<'a> Fn(
+                &Bound<'a, PyType>,
+                &Bound<'a, PyBaseException>,
+                Option<&Bound<'a, PyTraceback>>,
+            ) -> PyResult<()>
+            + Send
+            + 'static,
+    {
+        let excepthook = PyCFunction::new_closure(
+            self,
+            Some(ffi::c_str!("excepthook")),
+            None,
+            move |args, _kwargs| -> PyResult<()> {
+                let py = args.py();
+                let (ty, value, traceback) = args.extract::<(
+                    Bound<'_, PyType>,
+                    Bound<'_, PyBaseException>,
+                    Option<Bound<'_, PyTraceback>>,
+                )>()?;
+                if let Err(err) = hook(&ty, &value, traceback.as_ref()) {
+                    // the hook must not raise, so report its error with the default hook
+                    let default = py.import("sys")?.getattr(intern!(py, "__excepthook__"))?;
+                    default.call1((err.get_type(py), err.value(py), err.traceback(py)))?;
+                }
+                Ok(())
+            },
+        )?;
+        let sys = self.import("sys")?;
+        let previous = sys.getattr(intern!(self, "excepthook"))?;
+        sys.setattr(intern!(self, "excepthook"), excepthook)?;
+        Ok(previous)
+    }
+
+    /// Restores `sys.excepthook` to `previous`, the hook returned by [`Python::set_excepthook`].
+    pub fn reset_excepthook(self, previous: &Bound<'py, PyAny>) -> PyResult<()> {
+        self.import("sys")?
+            .setattr(intern!(self, "excepthook"), previous)
+    }
+
+    /// Compiles a Python expression once, so that it can be evaluated repeatedly with
+    /// [`CompiledExpr::eval`] without being parsed again.
+    ///
+    /// Syntax errors in `code` are raised here rather than when the expression is evaluated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pyo3::prelude::*;
+    /// # use pyo3::ffi::c_str;
+    /// # use pyo3::types::IntoPyDict;
+    /// # Python::with_gil(|py| {
+    /// let expr = py.compile_expr(c_str!("x * 2 + 1")).unwrap();
+    /// for x in 0..3 {
+    ///     let locals = [("x", x)].into_py_dict(py).unwrap();
+    ///     let result: i64 = expr.eval(py, None, Some(&locals)).unwrap().extract().unwrap();
+    ///     assert_eq!(result, x * 2 + 1);
+    /// }
+    /// # });
+    /// ```
+    pub fn compile_expr(self, code: &CStr) -> PyResult<CompiledExpr> {
+        self.compile_code(code, ffi::Py_eval_input)
+            .map(|code_obj| CompiledExpr(code_obj.unbind()))
+    }
+
     /// Deprecated name for [`Python::eval`].
     #[deprecated(since = "0.23.0", note = "renamed to `Python::eval`")]
     #[track_caller]
@@ -636,6 +834,25 @@ impl<'py> Python<'py> {
         start: c_int,
         globals: Option<&Bound<'py, PyDict>>,
         locals: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let code_obj = self.compile_code(code, start)?;
+        self.eval_code(&code_obj, globals, locals)
+    }
+
+    /// Compiles `code` to a code object; `start` is as for [`Python::run_code`].
+    fn compile_code(self, code: &CStr, start: c_int) -> PyResult<Bound<'py, PyAny>> {
+        unsafe {
+            ffi::Py_CompileString(code.as_ptr(), ffi::c_str!("<string>").as_ptr(), start)
+                .assume_owned_or_err(self)
+        }
+    }
+
+    /// Evaluates a compiled code object; `globals` and `locals` are as for [`Python::run_code`].
+    fn eval_code(
+        self,
+        code_obj: &Bound<'py, PyAny>,
+        globals: Option<&Bound<'py, PyDict>>,
+        locals: Option<&Bound<'py, PyDict>>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let mptr = unsafe {
             ffi::compat::PyImport_AddModuleRef(ffi::c_str!("__main__").as_ptr())
@@ -678,15 +895,9 @@ impl<'py> Python<'py> {
             })?;
         }
 
-        let code_obj = unsafe {
-            ffi::Py_CompileString(code.as_ptr(), ffi::c_str!("<string>").as_ptr(), start)
-                .assume_owned_or_err(self)?
-        };
-
         unsafe {
             ffi::PyEval_EvalCode(code_obj.as_ptr(), globals.as_ptr(), locals.as_ptr())
                 .assume_owned_or_err(self)
-                .downcast_into_unchecked()
         }
     }
 
@@ -730,6 +941,135 @@ impl<'py> Python<'py> {
         self.import(name.into_py(self))
     }
 
+    /// Imports a module from Python source code, without searching `sys.path`.
+    ///
+    /// If a module named `module_name` is already present in `sys.modules`, it is returned and
+    /// `code` is not executed again. Otherwise `code` is compiled and executed in a new module,
+    /// as with [`PyModule::from_code`], which is registered in `sys.modules` so later `import`
+    /// statements find it.
+    ///
+    /// <div class="information">
+    ///     <div class="tooltip compile_fail" style="">&#x26a0; &#xfe0f;</div>
+    /// </div><div class="example-wrap" style="display:inline-block"><pre class="compile_fail" style="white-space:normal;font:inherit;">
+    //
+    ///  <strong>Warning</strong>: This will compile and execute code. <strong>Never</strong> pass untrusted code to this function!
+    ///
+    /// </pre></div>
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyErr` if:
+    /// - `code` is not syntactically correct Python, in which case a `SyntaxError` is raised.
+    /// - Any Python exceptions are raised while initializing the module.
+    /// - Any of the arguments contain a nul byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| -> PyResult<()> {
+    ///     let plugin = py.import_from_code("def answer():\n    return 42\n", "plugin.py", "plugin")?;
+    ///     assert_eq!(plugin.getattr("answer")?.call0()?.extract::<i32>()?, 42);
+    ///
+    ///     // the module can now be imported by Python code
+    ///     let answer = py.import("plugin")?.getattr("answer")?;
+    ///     assert_eq!(answer.call0()?.extract::<i32>()?, 42);
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    pub fn import_from_code(
+        self,
+        code: &str,
+        file_name: &str,
+        module_name: &str,
+    ) -> PyResult<Bound<'py, PyModule>> {
+        let modules = unsafe {
+            ffi::PyImport_GetModuleDict()
+                .assume_borrowed_or_err(self)?
+                .downcast_unchecked::<PyDict>()
+        };
+        if let Some(module) = modules.get_item(module_name)? {
+            return module.downcast_into().map_err(Into::into);
+        }
+
+        let code = CString::new(code)?;
+        let file_name = CString::new(file_name)?;
+        let module_name = CString::new(module_name)?;
+        PyModule::from_code(self, &code, &file_name, &module_name)
+    }
+
+    /// Looks up the builtin object `name`, such as `len` or `range`.
+    ///
+    /// The builtins of the currently executing Python frame are used, so this sees the same
+    /// builtins as the Python code which called into Rust, even if that code runs with a replaced
+    /// `__builtins__`. Outside of any frame the interpreter's `builtins` module is used.
+    ///
+    /// Raises `NameError` if there is no builtin with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pyo3::prelude::*;
+    /// # Python::with_gil(|py| -> PyResult<()> {
+    /// let len = py.builtin("len")?;
+    /// assert_eq!(len.call1(("abc",))?.extract::<usize>()?, 3);
+    /// # Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub fn builtin(self, name: &str) -> PyResult<Bound<'py, PyAny>> {
+        // Safety: `PyEval_GetBuiltins` returns a borrowed reference to a dict, and is never null
+        let builtins = unsafe {
+            ffi::PyEval_GetBuiltins()
+                .assume_borrowed(self)
+                .downcast_unchecked::<PyDict>()
+        };
+        builtins
+            .get_item(name)?
+            .ok_or_else(|| PyNameError::new_err(format!("name '{}' is not defined", name)))
+    }
+
+    /// Appends `path` to `sys.path`, making the modules in that directory importable.
+    ///
+    /// As in Python, the path is not required to exist, and appending a path which is already
+    /// present in `sys.path` adds it a second time.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pyo3::prelude::*;
+    /// # use std::path::Path;
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     py.sys_path_append(Path::new("./plugins"))?;
+    ///     let plugin = py.import("my_plugin")?;
+    ///     # let _ = plugin;
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    pub fn sys_path_append(self, path: &Path) -> PyResult<()> {
+        self.sys_path()?.append(path.as_os_str())
+    }
+
+    /// Inserts `path` into `sys.path` at `index`, making the modules in that directory importable.
+    ///
+    /// Inserting at index 0 gives the modules in `path` priority over all others. As with
+    /// [`sys_path_append`](Python::sys_path_append), the path is not required to exist and
+    /// duplicates are permitted.
+    pub fn sys_path_insert(self, index: usize, path: &Path) -> PyResult<()> {
+        self.sys_path()?.insert(index, path.as_os_str())
+    }
+
+    fn sys_path(self) -> PyResult<Bound<'py, PyList>> {
+        self.import("sys")?
+            .getattr(intern!(self, "path"))?
+            .downcast_into()
+            .map_err(Into::into)
+    }
+
     /// Gets the Python builtin value `None`.
     #[allow(non_snake_case)] // the Python keyword starts with uppercase
     #[inline]
@@ -837,6 +1177,143 @@ impl<'py> Python<'py> {
     pub fn check_signals(self) -> PyResult<()> {
         err::error_on_minusone(self, unsafe { ffi::PyErr_CheckSignals() })
     }
+
+    /// Marks the start of a recursive call from Rust code, raising `RecursionError` if the
+    /// interpreter's recursion limit would be exceeded.
+    ///
+    /// Deeply recursive Rust code (e.g. conversions of arbitrarily nested Python containers) can
+    /// otherwise overflow the C stack and crash the interpreter. The recursive call is left again
+    /// when the returned [`RecursionGuard`] is dropped, so the guard should be kept alive for the
+    /// duration of the recursive call.
+    ///
+    /// `where_` is appended to the `RecursionError` message, which reads
+    /// `maximum recursion depth exceeded` followed by `where_`. It should therefore start with a
+    /// space, e.g. `" while converting list"`.
+    ///
+    /// This function calls [`Py_EnterRecursiveCall()`][1].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::ffi::c_str;
+    /// use pyo3::types::PyList;
+    ///
+    /// fn depth(obj: &Bound<'_, PyAny>) -> PyResult<usize> {
+    ///     let _guard = obj.py().enter_recursive_call(c_str!(" while measuring depth"))?;
+    ///     let mut max = 0;
+    ///     if let Ok(list) = obj.downcast::<PyList>() {
+    ///         for item in list {
+    ///             max = max.max(depth(&item)? + 1);
+    ///         }
+    ///     }
+    ///     Ok(max)
+    /// }
+    ///
+    /// Python::with_gil(|py| {
+    ///     let nested = py.eval(c_str!("[[1], [[2]]]"), None, None).unwrap();
+    ///     assert_eq!(depth(&nested).unwrap(), 3);
+    /// });
+    /// ```
+    ///
+    /// [1]: https://docs.python.org/3/c-api/exceptions.html#c.Py_EnterRecursiveCall
+    #[cfg(Py_3_9)]
+    pub fn enter_recursive_call(self, where_: &CStr) -> PyResult<RecursionGuard<'py>> {
+        if unsafe { ffi::Py_EnterRecursiveCall(where_.as_ptr()) } != 0 {
+            // the recursion limit has been reached, so avoid calling into Python
+            return Err(PyErr::fetch_no_panic_check(self));
+        }
+        Ok(RecursionGuard(self))
+    }
+
+    /// Returns the statistics of the garbage collector for each generation.
+    ///
+    /// This is equivalent to the Python expression `gc.get_stats()`, with each per-generation
+    /// dictionary read into a [`GcGenerationStats`]. The number of generations depends on the
+    /// Python version, so callers should not assume a fixed length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| -> PyResult<()> {
+    ///     for (generation, stats) in py.gc_stats()?.iter().enumerate() {
+    ///         println!(
+    ///             "generation {}: {} collections, {} collected, {} uncollectable",
+    ///             generation, stats.collections, stats.collected, stats.uncollectable
+    ///         );
+    ///     }
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    #[cfg(not(any(PyPy, GraalPy)))]
+    pub fn gc_stats(self) -> PyResult<Vec<GcGenerationStats>> {
+        let stats = self.import("gc")?.call_method0("get_stats")?;
+        stats
+            .try_iter()?
+            .map(|generation| {
+                let generation = generation?;
+                let get = |key: &str| generation.get_item(key)?.extract::<usize>();
+                Ok(GcGenerationStats {
+                    collections: get("collections")?,
+                    collected: get("collected")?,
+                    uncollectable: get("uncollectable")?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Statistics of one garbage collector generation, as returned by [`Python::gc_stats`].
+///
+/// The fields correspond to the keys of the dictionaries returned by Python's `gc.get_stats()`.
+#[cfg(not(any(PyPy, GraalPy)))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GcGenerationStats {
+    /// The number of times this generation was collected.
+    pub collections: usize,
+    /// The total number of objects collected inside this generation.
+    pub collected: usize,
+    /// The total number of objects which were found to be uncollectable inside this generation.
+    pub uncollectable: usize,
+}
+
+/// A Python expression compiled with [`Python::compile_expr`].
+///
+/// This holds the compiled code object, so it can be stored and sent between threads and
+/// evaluated any number of times against different namespaces.
+#[derive(Debug)]
+pub struct CompiledExpr(Py<PyAny>);
+
+impl CompiledExpr {
+    /// Evaluates the expression in the given context and returns the result.
+    ///
+    /// `globals` and `locals` behave as for [`Python::eval`].
+    pub fn eval<'py>(
+        &self,
+        py: Python<'py>,
+        globals: Option<&Bound<'py, PyDict>>,
+        locals: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        py.eval_code(self.0.bind(py), globals, locals)
+    }
+}
+
+/// Guard for a recursive call started with [`Python::enter_recursive_call`].
+///
+/// The recursive call is left when this guard is dropped.
+#[cfg(Py_3_9)]
+#[must_use = "the recursive call is left immediately if the guard is not kept alive"]
+pub struct RecursionGuard<'py>(Python<'py>);
+
+#[cfg(Py_3_9)]
+impl Drop for RecursionGuard<'_> {
+    fn drop(&mut self) {
+        unsafe { ffi::Py_LeaveRecursiveCall() }
+    }
 }
 
 impl<'unbound> Python<'unbound> {
@@ -865,7 +1342,7 @@ impl<'unbound> Python<'unbound> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{IntoPyDict, PyList};
+    use crate::types::IntoPyDict;
 
     #[test]
     fn test_eval() {
@@ -907,6 +1384,96 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_compile_expr() {
+        fn assert_send<T: Send + Sync>() {}
+        assert_send::<CompiledExpr>();
+
+        Python::with_gil(|py| {
+            let expr = py.compile_expr(ffi::c_str!("x * y + offset")).unwrap();
+            let globals = [("offset", 100)].into_py_dict(py).unwrap();
+            for (x, y) in [(1, 2), (3, 4), (-5, 6)] {
+                let locals = [("x", x), ("y", y)].into_py_dict(py).unwrap();
+                let result: i32 = expr
+                    .eval(py, Some(&globals), Some(&locals))
+                    .unwrap()
+                    .extract()
+                    .unwrap();
+                assert_eq!(result, x * y + 100);
+            }
+
+            // evaluation errors surface per evaluation
+            let locals = [("x", 1)].into_py_dict(py).unwrap();
+            let err = expr.eval(py, Some(&globals), Some(&locals)).unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyNameError>(py));
+
+            // syntax errors surface when compiling
+            let err = py.compile_expr(ffi::c_str!("x +")).unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PySyntaxError>(py));
+
+            // statements are not expressions
+            assert!(py.compile_expr(ffi::c_str!("x = 1")).is_err());
+        });
+    }
+
+    #[test]
+    fn test_builtin() {
+        use crate::types::PyCFunction;
+
+        Python::with_gil(|py| {
+            let len = py.builtin("len").unwrap();
+            assert_eq!(len.call1(("abc",)).unwrap().extract::<usize>().unwrap(), 3);
+
+            let err = py.builtin("not_a_builtin").unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyNameError>(py));
+            assert_eq!(
+                err.to_string(),
+                "NameError: name 'not_a_builtin' is not defined"
+            );
+
+            // called from Python code, the builtins of the calling frame are used
+            let get_len =
+                PyCFunction::new_closure(py, None, None, |args, _kwargs| -> PyResult<PyObject> {
+                    Ok(args.py().builtin("len")?.unbind())
+                })
+                .unwrap();
+            let builtins = PyDict::new(py);
+            builtins.set_item("len", "shadowed").unwrap();
+            builtins.set_item("get_len", get_len).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("__builtins__", builtins).unwrap();
+            let result = py
+                .eval(ffi::c_str!("get_len()"), Some(&globals), None)
+                .unwrap();
+            assert_eq!(result.extract::<String>().unwrap(), "shadowed");
+        });
+    }
+
+    #[test]
+    fn test_eval_restricted() {
+        Python::with_gil(|py| {
+            let result: usize = py
+                .eval_restricted(ffi::c_str!("len([1, 2, 3])"), &["len", "not_a_builtin"])
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(result, 3);
+
+            for code in [ffi::c_str!("open('file')"), ffi::c_str!("__import__('os')")] {
+                let err = py.eval_restricted(code, &["len"]).unwrap_err();
+                assert!(err.is_instance_of::<crate::exceptions::PyNameError>(py));
+            }
+
+            // the real builtins are not reachable through `__builtins__`
+            let builtins = py
+                .eval_restricted(ffi::c_str!("__builtins__"), &["len"])
+                .unwrap();
+            let builtins = builtins.downcast::<PyDict>().unwrap();
+            assert_eq!(builtins.len(), 1);
+            assert!(builtins.contains("len").unwrap());
+        });
+    }
+
     #[test]
     #[cfg(not(target_arch = "wasm32"))] // We are building wasm Python with pthreads disabled
     fn test_allow_threads_releases_and_acquires_gil() {
@@ -1002,6 +1569,36 @@ mod tests {
         });
     }
 
+    #[test]
+    #[cfg(all(Py_3_9, not(target_arch = "wasm32")))] // We are building wasm Python with pthreads disabled
+    fn test_enter_recursive_call() {
+        fn recurse(py: Python<'_>) -> PyResult<()> {
+            let _guard = py.enter_recursive_call(ffi::c_str!(" while recursing in a test"))?;
+            recurse(py)
+        }
+
+        // run on a thread with a large stack, so that unoptimized builds reach the recursion limit
+        // before overflowing the stack
+        std::thread::Builder::new()
+            .stack_size(64 * 1024 * 1024)
+            .spawn(|| {
+                Python::with_gil(|py| {
+                    let err = recurse(py).unwrap_err();
+                    assert!(err.is_instance_of::<crate::exceptions::PyRecursionError>(py));
+                    assert!(err
+                        .to_string()
+                        .ends_with("maximum recursion depth exceeded while recursing in a test"));
+
+                    // all guards have been dropped, so entering a recursive call succeeds again
+                    let guard = py.enter_recursive_call(ffi::c_str!("")).unwrap();
+                    drop(guard);
+                })
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
     #[test]
     fn test_py_run_inserts_globals() {
         use crate::types::dict::PyDictMethods;
@@ -1063,4 +1660,152 @@ cls.func()
             runner.reproducer(py).unwrap();
         });
     }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))] // no filesystem access
+    fn test_sys_path_append_and_insert() {
+        use crate::tests::common::generate_unique_module_name;
+
+        let module_name = generate_unique_module_name("sys_path_module");
+        let module_name = module_name.to_str().unwrap();
+        let dir = std::env::temp_dir().join(format!("pyo3_{}", module_name));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(format!("{}.py", module_name)), "VALUE = 42\n").unwrap();
+
+        Python::with_gil(|py| {
+            let sys_path = py.sys_path().unwrap();
+            let len = sys_path.len();
+
+            py.sys_path_append(&dir).unwrap();
+            assert_eq!(sys_path.len(), len + 1);
+            assert_eq!(
+                sys_path.get_item(len).unwrap().extract::<String>().unwrap(),
+                dir.to_str().unwrap()
+            );
+            let module = py.import(module_name).unwrap();
+            assert_eq!(
+                module.getattr("VALUE").unwrap().extract::<i32>().unwrap(),
+                42
+            );
+
+            // duplicates and non-existent paths are permitted
+            py.sys_path_insert(0, &dir).unwrap();
+            py.sys_path_insert(0, &dir.join("missing")).unwrap();
+            assert_eq!(sys_path.len(), len + 3);
+            assert_eq!(
+                sys_path.get_item(1).unwrap().extract::<String>().unwrap(),
+                dir.to_str().unwrap()
+            );
+
+            for path in [&dir, &dir, &dir.join("missing")] {
+                sys_path
+                    .call_method1("remove", (path.as_os_str(),))
+                    .unwrap();
+            }
+        });
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_with_gil_detached() {
+        let dict: Py<PyDict> = Python::with_gil_detached(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item("a", 1).unwrap();
+            dict
+        });
+
+        // the handle can be sent to another thread and bound again there
+        let dict = std::thread::spawn(move || {
+            Python::with_gil(|py| dict.bind(py).set_item("b", 2).unwrap());
+            dict
+        })
+        .join()
+        .unwrap();
+
+        Python::with_gil(|py| {
+            let dict = dict.bind(py);
+            assert_eq!(dict.len(), 2);
+            assert_eq!(
+                dict.get_item("b")
+                    .unwrap()
+                    .unwrap()
+                    .extract::<i32>()
+                    .unwrap(),
+                2
+            );
+        });
+
+        let list: Py<PyList> =
+            Python::try_with_gil_detached(|py| PyList::new(py, [1, 2, 3])).unwrap();
+        Python::with_gil(|py| assert_eq!(list.bind(py).len(), 3));
+
+        let err = Python::try_with_gil_detached(|py| py.import("not_a_module")).unwrap_err();
+        Python::with_gil(|py| {
+            assert!(err.is_instance_of::<crate::exceptions::PyModuleNotFoundError>(py))
+        });
+    }
+
+    #[test]
+    #[cfg(not(any(PyPy, GraalPy)))]
+    fn test_gc_stats() {
+        Python::with_gil(|py| {
+            let before = py.gc_stats().unwrap();
+            assert!(!before.is_empty());
+
+            py.import("gc")
+                .unwrap()
+                .call_method1("collect", (before.len() - 1,))
+                .unwrap();
+
+            let after = py.gc_stats().unwrap();
+            assert_eq!(after.len(), before.len());
+            let (before, after) = (before.last().unwrap(), after.last().unwrap());
+            assert!(after.collections > before.collections);
+            assert!(after.collected >= before.collected);
+            assert!(after.uncollectable >= before.uncollectable);
+        });
+    }
+
+    #[test]
+    fn test_import_from_code() {
+        use crate::tests::common::generate_unique_module_name;
+
+        let module_name = generate_unique_module_name("import_from_code");
+        let module_name = module_name.to_str().unwrap();
+
+        Python::with_gil(|py| {
+            let module = py
+                .import_from_code("VALUE = [1]\n", "plugin.py", module_name)
+                .unwrap();
+            assert_eq!(
+                module
+                    .getattr("VALUE")
+                    .unwrap()
+                    .extract::<Vec<i32>>()
+                    .unwrap(),
+                [1]
+            );
+            assert!(py.import(module_name).unwrap().is(&module));
+
+            // the cached module is returned, without executing the new code
+            let again = py
+                .import_from_code("VALUE = [2]\n", "plugin.py", module_name)
+                .unwrap();
+            assert!(again.is(&module));
+            assert_eq!(
+                again
+                    .getattr("VALUE")
+                    .unwrap()
+                    .extract::<Vec<i32>>()
+                    .unwrap(),
+                [1]
+            );
+
+            let err = py
+                .import_from_code("def (:\n", "broken.py", "pyo3_broken_import_from_code")
+                .unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PySyntaxError>(py));
+        });
+    }
 }
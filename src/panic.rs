@@ -1,7 +1,12 @@
 //! Helper to convert Rust panics to Python exceptions.
-use crate::exceptions::PyBaseException;
-use crate::PyErr;
+use crate::exceptions::{PyBaseException, PyTypeError};
+use crate::types::{PyAnyMethods, PyType, PyTypeMethods};
+use crate::{Bound, Py, PyErr, PyResult, Python};
 use std::any::Any;
+use std::sync::{Mutex, PoisonError};
+
+/// The exception type registered with [`set_panic_exception_type`], if any.
+static PANIC_EXCEPTION_TYPE: Mutex<Option<Py<PyType>>> = Mutex::new(None);
 
 pyo3_exception!(
     "
@@ -19,14 +24,82 @@ impl PanicException {
     /// Creates a new PanicException from a panic payload.
     ///
     /// Attempts to format the error in the same way panic does.
+    ///
+    /// The exception is of the type registered with [`set_panic_exception_type`], if any.
     #[cold]
-    pub(crate) fn from_panic_payload(payload: Box<dyn Any + Send + 'static>) -> PyErr {
-        if let Some(string) = payload.downcast_ref::<String>() {
-            Self::new_err((string.clone(),))
+    pub(crate) fn from_panic_payload(
+        py: Python<'_>,
+        payload: Box<dyn Any + Send + 'static>,
+    ) -> PyErr {
+        let msg = if let Some(string) = payload.downcast_ref::<String>() {
+            string.clone()
         } else if let Some(s) = payload.downcast_ref::<&str>() {
-            Self::new_err((s.to_string(),))
+            s.to_string()
         } else {
-            Self::new_err(("panic from Rust code",))
+            "panic from Rust code".to_owned()
+        };
+        let ty = PANIC_EXCEPTION_TYPE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .map(|ty| ty.clone_ref(py));
+        match ty {
+            Some(ty) => PyErr::from_type(ty.into_bound(py), (msg,)),
+            None => Self::new_err((msg,)),
         }
     }
 }
+
+/// Sets the exception type raised when Rust code called from Python panics.
+///
+/// By default panics are converted to [`PanicException`]. Registering a custom exception type
+/// lets Python code handle Rust panics distinctly, e.g. with `except MyRustPanic`. The type
+/// applies process-wide to all panics converted afterwards; pass `PanicException`'s own type
+/// to restore the default behavior.
+///
+/// Note that PyO3 only resumes unwinding when a [`PanicException`] is fetched back into Rust
+/// (see [`PyErr::fetch`]), so errors of a custom type propagate through Rust like any other
+/// Python exception.
+///
+/// Returns a `TypeError` if `ty` is not a subclass of `BaseException`.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::create_exception;
+/// use pyo3::exceptions::PyException;
+///
+/// create_exception!(my_module, RustPanic, PyException);
+///
+/// Python::with_gil(|py| -> PyResult<()> {
+///     pyo3::panic::set_panic_exception_type(py, &py.get_type::<RustPanic>())?;
+///     # pyo3::panic::set_panic_exception_type(
+///     #     py,
+///     #     &py.get_type::<pyo3::panic::PanicException>(),
+///     # )?;
+///     Ok(())
+/// })
+/// # .unwrap();
+/// ```
+pub fn set_panic_exception_type(py: Python<'_>, ty: &Bound<'_, PyType>) -> PyResult<()> {
+    if !ty.is_subclass_of::<PyBaseException>()? {
+        return Err(PyTypeError::new_err(format!(
+            "panic exception type must derive from BaseException, got {}",
+            ty.repr()?
+        )));
+    }
+    let ty = if ty.is(&py.get_type::<PanicException>()) {
+        None
+    } else {
+        Some(ty.clone().unbind())
+    };
+    // the previous type is dropped only once the lock has been released
+    let _previous = std::mem::replace(
+        &mut *PANIC_EXCEPTION_TYPE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner),
+        ty,
+    );
+    Ok(())
+}
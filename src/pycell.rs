@@ -367,15 +367,12 @@ where
     /// # });
     /// ```
     pub fn into_super(self) -> PyRef<'p, U> {
-        let py = self.py();
-        PyRef {
-            inner: unsafe {
-                ManuallyDrop::new(self)
-                    .as_ptr()
-                    .assume_owned_unchecked(py)
-                    .downcast_into_unchecked()
-            },
-        }
+        // `T` and `U` share the same borrow checker, unless `T` is a mutable class extending a
+        // frozen base. In that case the borrow on `T` has to be released, as `U` does not track
+        // borrows.
+        let base = PyRef::borrow(self.inner.as_super());
+        drop(self);
+        base
     }
 
     /// Borrows a shared reference to `PyRef<T::BaseType>`.
@@ -680,6 +677,17 @@ impl<T: PyClass<Frozen = False> + fmt::Debug> fmt::Debug for PyRefMut<'_, T> {
     }
 }
 
+/// The borrow state of a pyclass instance, as returned by [`Bound::borrow_count`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorrowState {
+    /// The value is not currently borrowed.
+    Unused,
+    /// The value is immutably borrowed by the given number of [`PyRef`]s.
+    Shared(usize),
+    /// The value is mutably borrowed by a [`PyRefMut`].
+    Exclusive,
+}
+
 /// An error type returned by [`Bound::try_borrow`].
 ///
 /// If this error is allowed to bubble up into Python code it will raise a `RuntimeError`.
@@ -14,7 +14,7 @@ use crate::type_object::{PyLayout, PySizedLayout};
 use crate::types::{PyType, PyTypeMethods};
 use crate::{ffi, PyClass, PyTypeInfo, Python};
 
-use super::{PyBorrowError, PyBorrowMutError};
+use super::{BorrowState, PyBorrowError, PyBorrowMutError};
 
 pub trait PyClassMutability {
     // The storage for this inheritance layer. Only the first mutable class in
@@ -91,6 +91,13 @@ impl BorrowFlag {
         // relaxed load is OK but decrements must happen-before the next read
         self.0.fetch_sub(1, Ordering::Release);
     }
+    fn state(&self) -> BorrowState {
+        match self.0.load(Ordering::Acquire) {
+            BorrowFlag::UNUSED => BorrowState::Unused,
+            BorrowFlag::HAS_MUTABLE_BORROW => BorrowState::Exclusive,
+            count => BorrowState::Shared(count),
+        }
+    }
 }
 
 pub struct EmptySlot(());
@@ -109,6 +116,8 @@ pub trait PyClassBorrowChecker {
     fn try_borrow_mut(&self) -> Result<(), PyBorrowMutError>;
     /// Decremements mutable borrow count
     fn release_borrow_mut(&self);
+    /// Reads the current borrow state without taking a borrow
+    fn borrow_state(&self) -> BorrowState;
 }
 
 impl PyClassBorrowChecker for EmptySlot {
@@ -134,6 +143,11 @@ impl PyClassBorrowChecker for EmptySlot {
     fn release_borrow_mut(&self) {
         unreachable!()
     }
+
+    #[inline]
+    fn borrow_state(&self) -> BorrowState {
+        BorrowState::Unused
+    }
 }
 
 impl PyClassBorrowChecker for BorrowChecker {
@@ -172,6 +186,10 @@ impl PyClassBorrowChecker for BorrowChecker {
     fn release_borrow_mut(&self) {
         self.0 .0.store(BorrowFlag::UNUSED, Ordering::Release)
     }
+
+    fn borrow_state(&self) -> BorrowState {
+        self.0.state()
+    }
 }
 
 pub trait GetBorrowChecker<T: PyClassImpl> {
@@ -540,6 +558,73 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_borrow_count() {
+        Python::with_gil(|py| {
+            let mmm = Py::new(
+                py,
+                PyClassInitializer::from(MutableBase)
+                    .add_subclass(MutableChildOfMutableBase)
+                    .add_subclass(MutableChildOfMutableChildOfMutableBase),
+            )
+            .unwrap();
+            let mmm_bound: &Bound<'_, MutableChildOfMutableChildOfMutableBase> = mmm.bind(py);
+            assert_eq!(mmm_bound.borrow_count(), BorrowState::Unused);
+
+            let first = mmm_bound.borrow();
+            let second = mmm_bound.borrow();
+            assert_eq!(mmm_bound.borrow_count(), BorrowState::Shared(2));
+            // the borrow flag is shared with the base classes
+            assert_eq!(
+                mmm_bound.as_super().as_super().borrow_count(),
+                BorrowState::Shared(2)
+            );
+            drop(first);
+            assert_eq!(mmm_bound.borrow_count(), BorrowState::Shared(1));
+            drop(second);
+            assert_eq!(mmm_bound.borrow_count(), BorrowState::Unused);
+
+            let refmut = mmm_bound.borrow_mut();
+            assert_eq!(mmm_bound.borrow_count(), BorrowState::Exclusive);
+            drop(refmut);
+            assert_eq!(mmm_bound.borrow_count(), BorrowState::Unused);
+
+            // immutable classes don't track borrows at all
+            let i = Bound::new(py, ImmutableBase).unwrap();
+            let _borrow = i.borrow();
+            assert_eq!(i.borrow_count(), BorrowState::Unused);
+
+            // but a mutable child of an immutable base does
+            let mi = Bound::new(
+                py,
+                PyClassInitializer::from(ImmutableBase).add_subclass(MutableChildOfImmutableBase),
+            )
+            .unwrap();
+            let _borrow = mi.borrow();
+            assert_eq!(mi.borrow_count(), BorrowState::Shared(1));
+        })
+    }
+
+    #[test]
+    fn test_into_super_with_immutable_base() {
+        Python::with_gil(|py| {
+            let mi = Bound::new(
+                py,
+                PyClassInitializer::from(ImmutableBase).add_subclass(MutableChildOfImmutableBase),
+            )
+            .unwrap();
+
+            let base: PyRef<'_, ImmutableBase> = mi.borrow().into_super();
+            // the child is no longer borrowed once only the base is accessible
+            assert_eq!(mi.borrow_count(), BorrowState::Unused);
+            assert!(mi.try_borrow_mut().is_ok());
+            drop(base);
+
+            assert_eq!(mi.borrow_count(), BorrowState::Unused);
+            assert!(mi.try_borrow_mut().is_ok());
+        })
+    }
+
     #[test]
     #[cfg(not(target_arch = "wasm32"))]
     fn test_thread_safety() {
@@ -4,10 +4,12 @@ use std::{cmp::Ordering, os::raw::c_int};
 
 mod create_type_object;
 mod gc;
+mod reduce;
 
 pub(crate) use self::create_type_object::{create_type_object, PyClassTypeObject};
 
 pub use self::gc::{PyTraverseError, PyVisit};
+pub use self::reduce::PyReduce;
 
 /// Types that can be used as Python classes.
 ///
@@ -0,0 +1,175 @@
+use crate::conversion::IntoPyObjectExt;
+use crate::types::{PyAny, PyTuple};
+use crate::{Bound, IntoPyObject, PyErr, PyResult, PyTypeInfo, Python};
+
+/// The return value of a `__reduce__` method, used to support [`pickle`] and [`copy`].
+///
+/// This describes how to reconstruct an object: by calling `callable(*args)` and then, if present,
+/// restoring `state` (via `__setstate__` if the class defines it, or by updating `__dict__`
+/// otherwise). Converting a `PyReduce` to Python produces the corresponding `(callable, args)` or
+/// `(callable, args, state)` tuple expected by the pickle protocol.
+///
+/// For `pickle` to be able to locate a `#[pyclass]` by name, it must be reachable at
+/// `<module>.<name>`, so combine this with `#[pyclass(module = "...")]` and add the class to that
+/// module.
+///
+/// # Example
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::pyclass::PyReduce;
+///
+/// #[pyclass(module = "geometry")]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[pymethods]
+/// impl Point {
+///     #[new]
+///     fn new(x: i32, y: i32) -> Self {
+///         Point { x, y }
+///     }
+///
+///     fn __reduce__<'py>(slf: PyRef<'py, Self>) -> PyResult<PyReduce<'py>> {
+///         PyReduce::from_class::<Self, _>(slf.py(), (slf.x, slf.y))
+///     }
+/// }
+/// # Python::with_gil(|py| {
+/// #     let point = Bound::new(py, Point::new(1, 2)).unwrap();
+/// #     pyo3::py_run!(py, point, r#"
+/// #         import copy
+/// #         other = copy.copy(point)
+/// #         assert other is not point
+/// #         assert type(other) is type(point)
+/// #         assert other.__reduce__()[1] == (1, 2)
+/// #     "#);
+/// # });
+/// ```
+///
+/// [`pickle`]: https://docs.python.org/3/library/pickle.html#object.__reduce__
+/// [`copy`]: https://docs.python.org/3/library/copy.html
+#[derive(Debug)]
+pub struct PyReduce<'py> {
+    callable: Bound<'py, PyAny>,
+    args: Bound<'py, PyTuple>,
+    state: Option<Bound<'py, PyAny>>,
+}
+
+impl<'py> PyReduce<'py> {
+    /// Reconstructs the object by calling `callable(*args)`.
+    pub fn new(callable: Bound<'py, PyAny>, args: Bound<'py, PyTuple>) -> Self {
+        PyReduce {
+            callable,
+            args,
+            state: None,
+        }
+    }
+
+    /// Reconstructs the object by calling the Python type of `T` with `args`, which must therefore
+    /// be accepted by its `#[new]` constructor.
+    pub fn from_class<T, A>(py: Python<'py>, args: A) -> PyResult<Self>
+    where
+        T: PyTypeInfo,
+        A: IntoPyObject<'py, Target = PyTuple, Output = Bound<'py, PyTuple>>,
+    {
+        Ok(Self::new(
+            T::type_object(py).into_any(),
+            args.into_pyobject_or_pyerr(py)?,
+        ))
+    }
+
+    /// Additionally restores `state` on the reconstructed object.
+    ///
+    /// The state is passed to `__setstate__` if the class defines it, otherwise it must be a
+    /// `dict` which is used to update the object's `__dict__`.
+    pub fn with_state<S>(self, state: S) -> PyResult<Self>
+    where
+        S: IntoPyObject<'py>,
+    {
+        let state = state.into_bound_py_any(self.callable.py())?;
+        Ok(PyReduce {
+            state: Some(state),
+            ..self
+        })
+    }
+}
+
+impl<'py> IntoPyObject<'py> for PyReduce<'py> {
+    type Target = PyTuple;
+    type Output = Bound<'py, PyTuple>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        match self.state {
+            Some(state) => (self.callable, self.args, state).into_pyobject(py),
+            None => (self.callable, self.args).into_pyobject(py),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use super::PyReduce;
+    use crate::types::{PyAnyMethods, PyTupleMethods};
+    use crate::{py_run, Bound, IntoPyObject, PyRef, PyResult, Python};
+
+    #[crate::pyclass(crate = "crate")]
+    struct Counter {
+        start: u32,
+        count: u32,
+    }
+
+    #[crate::pymethods(crate = "crate")]
+    impl Counter {
+        #[new]
+        fn new(start: u32) -> Self {
+            Counter { start, count: 0 }
+        }
+
+        fn __reduce__(slf: PyRef<'_, Self>) -> PyResult<PyReduce<'_>> {
+            PyReduce::from_class::<Self, _>(slf.py(), (slf.start,))?.with_state(slf.count)
+        }
+
+        fn __setstate__(&mut self, count: u32) {
+            self.count = count;
+        }
+    }
+
+    #[test]
+    fn test_reduce_tuple() {
+        Python::with_gil(|py| {
+            let counter = Bound::new(py, Counter::new(3)).unwrap();
+            let reduced = PyReduce::from_class::<Counter, _>(py, (3,))
+                .unwrap()
+                .into_pyobject(py)
+                .unwrap();
+            assert_eq!(reduced.len(), 2);
+            assert!(reduced.get_item(0).unwrap().is(&counter.get_type()));
+
+            let reduced = counter.call_method0("__reduce__").unwrap();
+            assert_eq!(reduced.len().unwrap(), 3);
+        });
+    }
+
+    #[test]
+    fn test_reduce_copy() {
+        Python::with_gil(|py| {
+            let counter = Bound::new(py, Counter::new(3)).unwrap();
+            counter.borrow_mut().count = 5;
+            let copied = py
+                .import("copy")
+                .unwrap()
+                .call_method1("deepcopy", (&counter,))
+                .unwrap()
+                .downcast_into::<Counter>()
+                .unwrap();
+            assert!(!copied.is(&counter));
+            assert_eq!(copied.borrow().start, 3);
+            assert_eq!(copied.borrow().count, 5);
+            py_run!(py, copied, "assert copied.__reduce__()[1:] == ((3,), 5)");
+        });
+    }
+}
@@ -145,6 +145,73 @@ impl<T: PyClass> PyClassInitializer<T> {
         PyClassInitializer::new(subclass_value, self)
     }
 
+    /// Constructs a new initializer for a subclass, computing the subclass value from the base
+    /// class value.
+    ///
+    /// This is like [`add_subclass`](Self::add_subclass), but the closure `f` receives a reference
+    /// to the base value, which is useful when fields of the subclass are derived from it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this initializer was created from an existing object, as a subclass cannot be
+    /// added to it.
+    ///
+    /// # Examples
+    /// ```
+    /// use pyo3::prelude::*;
+    ///
+    /// #[pyclass(subclass)]
+    /// struct Name {
+    ///     #[pyo3(get)]
+    ///     name: String,
+    /// }
+    ///
+    /// #[pyclass(extends=Name)]
+    /// struct Greeting {
+    ///     #[pyo3(get)]
+    ///     greeting: String,
+    /// }
+    ///
+    /// #[pymethods]
+    /// impl Greeting {
+    ///     #[new]
+    ///     fn new(name: String) -> PyClassInitializer<Self> {
+    ///         PyClassInitializer::from(Name { name }).add_subclass_with(|base| Greeting {
+    ///             greeting: format!("Hello, {}!", base.name),
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// fn main() -> PyResult<()> {
+    ///     Python::with_gil(|py| {
+    ///         let instance = py.get_type::<Greeting>().call1(("world",))?;
+    ///         assert_eq!(instance.getattr("name")?.extract::<String>()?, "world");
+    ///         assert_eq!(
+    ///             instance.getattr("greeting")?.extract::<String>()?,
+    ///             "Hello, world!"
+    ///         );
+    ///         Ok(())
+    ///     })
+    /// }
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn add_subclass_with<S, F>(self, f: F) -> PyClassInitializer<S>
+    where
+        S: PyClass<BaseType = T>,
+        S::BaseType: PyClassBaseType<Initializer = Self>,
+        F: FnOnce(&T) -> S,
+    {
+        let subclass_value = match &self.0 {
+            PyClassInitializerImpl::New { init, .. } => f(init),
+            // This is unsound; see https://github.com/PyO3/pyo3/issues/4452.
+            PyClassInitializerImpl::Existing(_) => {
+                panic!("you cannot add a subclass to an existing value")
+            }
+        };
+        PyClassInitializer::new(subclass_value, self)
+    }
+
     /// Creates a new PyCell and initializes it.
     pub(crate) fn create_class_object(self, py: Python<'_>) -> PyResult<Bound<'_, T>>
     where
@@ -288,4 +355,39 @@ mod tests {
             let _subclass = PyClassInitializer::from(base).add_subclass(SubClass { _data: 42 });
         });
     }
+
+    #[test]
+    #[should_panic]
+    fn add_subclass_with_to_py_is_unsound() {
+        Python::with_gil(|py| {
+            let base = Py::new(py, BaseClass {}).unwrap();
+            let _subclass =
+                PyClassInitializer::from(base).add_subclass_with(|_| SubClass { _data: 42 });
+        });
+    }
+
+    #[pyclass(crate = "crate", subclass)]
+    struct ValueClass {
+        value: i32,
+    }
+
+    #[pyclass(crate = "crate", extends=ValueClass)]
+    struct DoubledClass {
+        doubled: i32,
+    }
+
+    #[test]
+    fn add_subclass_with_reads_base() {
+        Python::with_gil(|py| {
+            let init =
+                PyClassInitializer::from(ValueClass { value: 21 }).add_subclass_with(|base| {
+                    DoubledClass {
+                        doubled: base.value * 2,
+                    }
+                });
+            let obj = Bound::new(py, init).unwrap();
+            assert_eq!(obj.borrow().doubled, 42);
+            assert_eq!(obj.borrow().as_super().value, 21);
+        });
+    }
 }
@@ -591,6 +591,211 @@ impl<T> MutexExt<T> for std::sync::Mutex<T> {
     }
 }
 
+/// A mutual exclusion lock which cooperates with the Python interpreter.
+///
+/// Unlike holding a [`std::sync::Mutex`] while attached to the interpreter, blocking on a
+/// `PyMutex` never deadlocks with the GIL (or, on the free-threaded build, with global
+/// synchronization events such as garbage collection): if the lock is contended, the calling
+/// thread detaches from the interpreter while it waits, like [`Python::allow_threads`].
+///
+/// On Python 3.13 and newer (outside of the limited API) this is backed by CPython's own
+/// [`PyMutex`][ffi::PyMutex], which takes a single byte. On older versions it falls back to a
+/// [`std::sync::Mutex`] locked with [`MutexExt::lock_py_attached`].
+///
+/// Like `PyMutex` in CPython, this lock is not poisoned if a thread panics while holding it.
+///
+/// # Example
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::sync::PyMutex;
+///
+/// #[pyclass(frozen)]
+/// struct Counter {
+///     count: PyMutex<u64>,
+/// }
+///
+/// #[pymethods]
+/// impl Counter {
+///     fn increment(&self, py: Python<'_>) -> u64 {
+///         let mut count = self.count.lock(py);
+///         *count += 1;
+///         *count
+///     }
+/// }
+/// # Python::with_gil(|py| {
+/// #     let counter = Bound::new(py, Counter { count: PyMutex::new(0) }).unwrap();
+/// #     pyo3::py_run!(py, counter, "assert counter.increment() == 1; assert counter.increment() == 2");
+/// # });
+/// ```
+pub struct PyMutex<T> {
+    #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+    mutex: UnsafeCell<crate::ffi::PyMutex>,
+    #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+    data: UnsafeCell<T>,
+    #[cfg(not(all(Py_3_13, not(Py_LIMITED_API))))]
+    inner: std::sync::Mutex<T>,
+}
+
+// SAFETY: the data is only accessed through a `PyMutexGuard`, which holds the lock.
+#[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+unsafe impl<T: Send> Send for PyMutex<T> {}
+#[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+unsafe impl<T: Send> Sync for PyMutex<T> {}
+
+impl<T> PyMutex<T> {
+    /// Creates a new unlocked mutex containing `value`.
+    pub const fn new(value: T) -> Self {
+        PyMutex {
+            #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+            mutex: UnsafeCell::new(crate::ffi::PyMutex::new()),
+            #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+            data: UnsafeCell::new(value),
+            #[cfg(not(all(Py_3_13, not(Py_LIMITED_API))))]
+            inner: std::sync::Mutex::new(value),
+        }
+    }
+
+    /// Consumes the mutex, returning the contained value.
+    pub fn into_inner(self) -> T {
+        #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+        {
+            self.data.into_inner()
+        }
+
+        #[cfg(not(all(Py_3_13, not(Py_LIMITED_API))))]
+        {
+            self.inner
+                .into_inner()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+        }
+    }
+
+    /// Acquires the lock, blocking the current thread until it is available.
+    ///
+    /// While blocked, the thread detaches from the Python interpreter so that other threads can
+    /// run Python code (including the thread currently holding this lock). The lock is released
+    /// when the returned guard is dropped.
+    ///
+    /// Locking a `PyMutex` which is already held by the current thread deadlocks.
+    pub fn lock(&self, py: Python<'_>) -> PyMutexGuard<'_, T> {
+        #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+        {
+            let _ = py;
+            // SAFETY: `PyMutex_Lock` detaches from the interpreter itself while it blocks.
+            unsafe { crate::ffi::PyMutex_Lock(self.mutex.get()) };
+            PyMutexGuard {
+                mutex: self,
+                _not_send: PhantomData,
+            }
+        }
+
+        #[cfg(not(all(Py_3_13, not(Py_LIMITED_API))))]
+        {
+            PyMutexGuard {
+                guard: self
+                    .inner
+                    .lock_py_attached(py)
+                    .unwrap_or_else(std::sync::PoisonError::into_inner),
+            }
+        }
+    }
+
+    /// Returns a mutable reference to the contained value.
+    ///
+    /// No locking is needed, because the mutable borrow of `self` guarantees exclusive access.
+    pub fn get_mut(&mut self) -> &mut T {
+        #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+        {
+            self.data.get_mut()
+        }
+
+        #[cfg(not(all(Py_3_13, not(Py_LIMITED_API))))]
+        {
+            self.inner
+                .get_mut()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+        }
+    }
+}
+
+impl<T: Default> Default for PyMutex<T> {
+    fn default() -> Self {
+        PyMutex::new(T::default())
+    }
+}
+
+impl<T> std::fmt::Debug for PyMutex<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PyMutex").finish_non_exhaustive()
+    }
+}
+
+/// An RAII guard for a locked [`PyMutex`], created by [`PyMutex::lock`].
+///
+/// The protected data can be accessed through the `Deref` and `DerefMut` implementations of
+/// this guard. The lock is released when the guard is dropped.
+#[must_use = "if unused the PyMutex will immediately unlock"]
+pub struct PyMutexGuard<'a, T> {
+    #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+    mutex: &'a PyMutex<T>,
+    // The lock must be released on the thread which acquired it.
+    #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+    _not_send: PhantomData<*const ()>,
+    #[cfg(not(all(Py_3_13, not(Py_LIMITED_API))))]
+    guard: std::sync::MutexGuard<'a, T>,
+}
+
+impl<T> std::ops::Deref for PyMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+        {
+            // SAFETY: the guard holds the lock
+            unsafe { &*self.mutex.data.get() }
+        }
+
+        #[cfg(not(all(Py_3_13, not(Py_LIMITED_API))))]
+        {
+            &self.guard
+        }
+    }
+}
+
+impl<T> std::ops::DerefMut for PyMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        #[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+        {
+            // SAFETY: the guard holds the lock
+            unsafe { &mut *self.mutex.data.get() }
+        }
+
+        #[cfg(not(all(Py_3_13, not(Py_LIMITED_API))))]
+        {
+            &mut self.guard
+        }
+    }
+}
+
+#[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+impl<T> Drop for PyMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: the guard holds the lock
+        unsafe { crate::ffi::PyMutex_Unlock(self.mutex.mutex.get()) };
+    }
+}
+
+// SAFETY: sharing the guard only gives out shared references to the data.
+#[cfg(all(Py_3_13, not(Py_LIMITED_API)))]
+unsafe impl<T: Sync> Sync for PyMutexGuard<'_, T> {}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for PyMutexGuard<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&**self, f)
+    }
+}
+
 #[cold]
 fn init_once_py_attached<F, T>(once: &Once, _py: Python<'_>, f: F)
 where
@@ -881,4 +1086,56 @@ mod tests {
         });
         assert!(*guard == 42);
     }
+
+    #[cfg(not(target_arch = "wasm32"))] // We are building wasm Python with pthreads disabled
+    #[test]
+    fn test_py_mutex() {
+        use std::sync::Barrier;
+
+        let barrier = Barrier::new(2);
+        let mutex = PyMutex::new(Vec::new());
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                Python::with_gil(|py| {
+                    let mut guard = mutex.lock(py);
+                    barrier.wait();
+                    // sleep to ensure the other thread actually blocks
+                    std::thread::sleep(std::time::Duration::from_millis(10));
+                    guard.push(1);
+                });
+            });
+            s.spawn(|| {
+                barrier.wait();
+                Python::with_gil(|py| {
+                    // blocks until the other thread releases the lock, without holding the GIL
+                    let mut guard = mutex.lock(py);
+                    assert_eq!(*guard, [1]);
+                    guard.push(2);
+                });
+            });
+        });
+
+        assert_eq!(mutex.into_inner(), [1, 2]);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))] // We are building wasm Python with pthreads disabled
+    #[test]
+    fn test_py_mutex_not_poisoned() {
+        let mut mutex = PyMutex::new(42);
+
+        std::thread::scope(|s| {
+            let result = s.spawn(|| {
+                Python::with_gil(|py| {
+                    let _guard = mutex.lock(py);
+                    panic!();
+                });
+            });
+            assert!(result.join().is_err());
+        });
+
+        Python::with_gil(|py| assert_eq!(*mutex.lock(py), 42));
+        *mutex.get_mut() += 1;
+        assert_eq!(mutex.into_inner(), 43);
+    }
 }
@@ -11,7 +11,10 @@ use crate::py_result_ext::PyResultExt;
 use crate::type_object::{PyTypeCheck, PyTypeInfo};
 #[cfg(not(any(PyPy, GraalPy)))]
 use crate::types::PySuper;
-use crate::types::{PyDict, PyIterator, PyList, PyString, PyType};
+use crate::types::{
+    PyBytes, PyBytesMethods, PyDict, PyDictMethods, PyIterator, PyList, PyListMethods, PyMapping,
+    PySequence, PyString, PyTuple, PyType,
+};
 use crate::{err, ffi, Borrowed, BoundObject, IntoPyObjectExt, Python};
 use std::cell::UnsafeCell;
 use std::cmp::Ordering;
@@ -118,6 +121,32 @@ pub trait PyAnyMethods<'py>: crate::sealed::Sealed {
     where
         N: IntoPyObject<'py, Target = PyString>;
 
+    /// Retrieves an attribute value, returning `None` if the attribute does not exist.
+    ///
+    /// This is equivalent to the Python expression `getattr(self, attr_name, None)`, except that a
+    /// `None`-valued attribute is returned as `Some`. Only `AttributeError` is suppressed; all
+    /// other exceptions are propagated. On Python 3.13 and newer this uses
+    /// `PyObject_GetOptionalAttr`, which avoids creating the `AttributeError` in the first place.
+    ///
+    /// To avoid repeated temporary allocations of Python strings, the [`intern!`] macro can be used
+    /// to intern `attr_name`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pyo3::{prelude::*, intern};
+    /// #
+    /// # Python::with_gil(|py| -> PyResult<()> {
+    /// let sys = py.import("sys")?;
+    /// assert!(sys.getattr_opt(intern!(py, "version"))?.is_some());
+    /// assert!(sys.getattr_opt("does_not_exist")?.is_none());
+    /// # Ok(())
+    /// # }).unwrap();
+    /// ```
+    fn getattr_opt<N>(&self, attr_name: N) -> PyResult<Option<Bound<'py, PyAny>>>
+    where
+        N: IntoPyObject<'py, Target = PyString>;
+
     /// Sets an attribute value.
     ///
     /// This is equivalent to the Python expression `self.attr_name = value`.
@@ -145,6 +174,33 @@ pub trait PyAnyMethods<'py>: crate::sealed::Sealed {
         N: IntoPyObject<'py, Target = PyString>,
         V: IntoPyObject<'py>;
 
+    /// Sets several attribute values.
+    ///
+    /// This is equivalent to calling [`setattr`](PyAnyMethods::setattr) for each `(name, value)`
+    /// pair of `attrs` in order.
+    ///
+    /// If setting an attribute fails, the error is returned immediately and the attributes which
+    /// were set before the failure are left in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pyo3::prelude::*;
+    /// #
+    /// # Python::with_gil(|py| -> PyResult<()> {
+    /// let ob = PyModule::new(py, "empty")?;
+    /// ob.setattr_many([("answer", 42), ("question", 0)])?;
+    /// assert_eq!(ob.getattr("answer")?.extract::<i32>()?, 42);
+    /// assert_eq!(ob.getattr("question")?.extract::<i32>()?, 0);
+    /// # Ok(())
+    /// # }).unwrap();
+    /// ```
+    fn setattr_many<I, N, V>(&self, attrs: I) -> PyResult<()>
+    where
+        I: IntoIterator<Item = (N, V)>,
+        N: IntoPyObject<'py, Target = PyString>,
+        V: IntoPyObject<'py>;
+
     /// Deletes an attribute.
     ///
     /// This is equivalent to the Python statement `del self.attr_name`.
@@ -617,6 +673,79 @@ pub trait PyAnyMethods<'py>: crate::sealed::Sealed {
         N: IntoPyObject<'py, Target = PyString>,
         A: PyCallArgs<'py>;
 
+    /// Calls a method on the object with positional arguments taken from an iterator.
+    ///
+    /// This is equivalent to the Python expression `self.name(*args)`, without having to build
+    /// the arguments tuple first. If the iterator reports an exact [`size_hint`], the tuple is
+    /// allocated with that size up front.
+    ///
+    /// [`size_hint`]: Iterator::size_hint
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator reports an exact `size_hint` but yields a different number of
+    /// elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyString;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let template = PyString::new(py, "{}-{}-{}");
+    ///     let parts = (1..=3).map(|i| i * 10);
+    ///     let result = template.call_method_iter("format", parts)?;
+    ///     assert_eq!(result.extract::<String>()?, "10-20-30");
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    fn call_method_iter<N, I>(&self, name: N, args: I) -> PyResult<Bound<'py, PyAny>>
+    where
+        N: IntoPyObject<'py, Target = PyString>,
+        I: IntoIterator,
+        I::Item: IntoPyObject<'py>;
+
+    /// Calls a method on the object with only keyword arguments.
+    ///
+    /// This is equivalent to the Python expression `self.name(**kwargs)`, where `kwargs` is built
+    /// from the given `(key, value)` pairs. If `kwargs` is empty, the method is called with no
+    /// arguments. If a value fails to convert to a Python object, a `TypeError` naming the key is
+    /// raised, with the conversion error as its cause.
+    ///
+    /// # Example: Calling a keyword-only method
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3_ffi::c_str;
+    /// use std::ffi::CStr;
+    ///
+    /// const CODE: &CStr = c_str!(r#"
+    /// class A:
+    ///     def method(self, *, greeting, name):
+    ///         return f"{greeting}, {name}!"
+    /// a = A()
+    /// "#);
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let module = PyModule::from_code(py, CODE, c_str!(""), c_str!(""))?;
+    ///     let instance = module.getattr("a")?;
+    ///     let result =
+    ///         instance.call_method_kw_only("method", [("greeting", "hello"), ("name", "world")])?;
+    ///     assert_eq!(result.extract::<String>()?, "hello, world!");
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    fn call_method_kw_only<'k, N, K, V>(&self, name: N, kwargs: K) -> PyResult<Bound<'py, PyAny>>
+    where
+        N: IntoPyObject<'py, Target = PyString>,
+        K: IntoIterator<Item = (&'k str, V)>,
+        V: IntoPyObject<'py>;
+
     /// Returns whether the object is considered to be true.
     ///
     /// This is equivalent to the Python expression `bool(self)`.
@@ -666,6 +795,10 @@ pub trait PyAnyMethods<'py>: crate::sealed::Sealed {
     /// This is typically a new iterator but if the argument is an iterator,
     /// this returns itself.
     ///
+    /// The iterator is created immediately, equivalent to the Python expression `iter(self)`, so
+    /// a non-iterable object fails here with a `TypeError` rather than on the first call to
+    /// `next`. If the object's `__iter__` raises, that exception is returned unchanged.
+    ///
     /// # Example: Checking a Python object for iterability
     ///
     /// ```rust
@@ -816,6 +949,27 @@ pub trait PyAnyMethods<'py>: crate::sealed::Sealed {
         T: PyTypeInfo;
 
     /// Like `downcast_exact` but takes ownership of `self`.
+    ///
+    /// On failure the error message names both the expected type and the actual type of the
+    /// object. The original object can be recovered from the error with
+    /// [`DowncastIntoError::into_inner`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::{PyBool, PyInt};
+    ///
+    /// Python::with_gil(|py| {
+    ///     let any = PyBool::new(py, true).to_owned().into_any();
+    ///
+    ///     let err = any.downcast_into_exact::<PyInt>().unwrap_err();
+    ///     assert_eq!(err.to_string(), "'bool' object cannot be converted to 'PyInt'");
+    ///
+    ///     let any = err.into_inner();
+    ///     assert!(any.downcast_into_exact::<PyBool>().is_ok());
+    /// });
+    /// ```
     fn downcast_into_exact<T>(self) -> Result<Bound<'py, T>, DowncastIntoError<'py>>
     where
         T: PyTypeInfo;
@@ -834,6 +988,50 @@ pub trait PyAnyMethods<'py>: crate::sealed::Sealed {
     /// Callers must ensure that the type is valid or risk type confusion.
     unsafe fn downcast_into_unchecked<T>(self) -> Bound<'py, T>;
 
+    /// Returns this object as a [`PyMapping`] if it supports the mapping protocol.
+    ///
+    /// This uses the C API's `PyMapping_Check`, which only tests whether the type implements
+    /// `__getitem__`. This is much cheaper than [`downcast::<PyMapping>`](PyAnyMethods::downcast),
+    /// which checks against `collections.abc.Mapping`, but it is also far less strict: sequences
+    /// such as `list` and `str` implement `__getitem__` too and so are reported as mappings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::{PyDict, PyList};
+    ///
+    /// Python::with_gil(|py| {
+    ///     assert!(PyDict::new(py).as_any().as_mapping_opt().is_some());
+    ///     // lists implement `__getitem__`, so also pass the check
+    ///     assert!(PyList::empty(py).as_any().as_mapping_opt().is_some());
+    ///     assert!(py.None().bind(py).as_mapping_opt().is_none());
+    /// });
+    /// ```
+    fn as_mapping_opt(&self) -> Option<&Bound<'py, PyMapping>>;
+
+    /// Returns this object as a [`PySequence`] if it supports the sequence protocol.
+    ///
+    /// This uses the C API's `PySequence_Check`, which tests whether the type implements
+    /// `__getitem__` and is not a `dict` subclass. Unlike
+    /// [`downcast::<PySequence>`](PyAnyMethods::downcast), no check against
+    /// `collections.abc.Sequence` is performed, so user-defined mapping classes which implement
+    /// `__getitem__` are also reported as sequences.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::{PyDict, PyList};
+    ///
+    /// Python::with_gil(|py| {
+    ///     assert!(PyList::empty(py).as_any().as_sequence_opt().is_some());
+    ///     assert!(PyDict::new(py).as_any().as_sequence_opt().is_none());
+    ///     assert!(py.None().bind(py).as_sequence_opt().is_none());
+    /// });
+    /// ```
+    fn as_sequence_opt(&self) -> Option<&Bound<'py, PySequence>>;
+
     /// Extracts some type from the Python object.
     ///
     /// This is a wrapper function around
@@ -850,6 +1048,16 @@ pub trait PyAnyMethods<'py>: crate::sealed::Sealed {
     /// This is equivalent to the Python expression `repr(self)`.
     fn repr(&self) -> PyResult<Bound<'py, PyString>>;
 
+    /// Computes the "repr" representation of self, encoded as UTF-8 bytes.
+    ///
+    /// Unlike converting the result of [`repr`](PyAnyMethods::repr) to a Rust string, this
+    /// preserves lone surrogates exactly by encoding them with the `surrogatepass` error handler,
+    /// so the result is not necessarily valid UTF-8. This is useful for logging the repr of
+    /// unusual objects faithfully.
+    ///
+    /// This is equivalent to the Python expression `repr(self).encode("utf-8", "surrogatepass")`.
+    fn repr_bytes(&self) -> PyResult<Vec<u8>>;
+
     /// Computes the "str" representation of self.
     ///
     /// This is equivalent to the Python expression `str(self)`.
@@ -884,6 +1092,13 @@ pub trait PyAnyMethods<'py>: crate::sealed::Sealed {
     ///
     /// This is equivalent to the Python expression `isinstance(self, T)`,
     /// if the type `T` is known at compile time.
+    ///
+    /// The type object of `T` is only looked up once: `#[pyclass]`es and types
+    /// from [`create_exception!`] and [`import_exception!`] cache it, and builtin types are
+    /// static. This makes the check cheap to repeat in hot loops.
+    ///
+    /// [`create_exception!`]: crate::create_exception
+    /// [`import_exception!`]: crate::import_exception
     fn is_instance_of<T: PyTypeInfo>(&self) -> bool;
 
     /// Checks whether this object is an instance of exactly type `T`.
@@ -975,6 +1190,45 @@ impl<'py> PyAnyMethods<'py> for Bound<'py, PyAny> {
         )
     }
 
+    fn getattr_opt<N>(&self, attr_name: N) -> PyResult<Option<Bound<'py, PyAny>>>
+    where
+        N: IntoPyObject<'py, Target = PyString>,
+    {
+        fn inner<'py>(
+            any: &Bound<'py, PyAny>,
+            attr_name: Borrowed<'_, 'py, PyString>,
+        ) -> PyResult<Option<Bound<'py, PyAny>>> {
+            #[cfg(Py_3_13)]
+            {
+                let mut result: *mut ffi::PyObject = std::ptr::null_mut();
+                match unsafe {
+                    ffi::PyObject_GetOptionalAttr(any.as_ptr(), attr_name.as_ptr(), &mut result)
+                } {
+                    1 => Ok(Some(unsafe { result.assume_owned(any.py()) })),
+                    0 => Ok(None),
+                    _ => Err(PyErr::fetch(any.py())),
+                }
+            }
+
+            #[cfg(not(Py_3_13))]
+            {
+                match any.getattr(attr_name) {
+                    Ok(value) => Ok(Some(value)),
+                    Err(err) if err.is_instance_of::<PyAttributeError>(any.py()) => Ok(None),
+                    Err(err) => Err(err),
+                }
+            }
+        }
+
+        inner(
+            self,
+            attr_name
+                .into_pyobject(self.py())
+                .map_err(Into::into)?
+                .as_borrowed(),
+        )
+    }
+
     fn setattr<N, V>(&self, attr_name: N, value: V) -> PyResult<()>
     where
         N: IntoPyObject<'py, Target = PyString>,
@@ -998,6 +1252,17 @@ impl<'py> PyAnyMethods<'py> for Bound<'py, PyAny> {
         )
     }
 
+    fn setattr_many<I, N, V>(&self, attrs: I) -> PyResult<()>
+    where
+        I: IntoIterator<Item = (N, V)>,
+        N: IntoPyObject<'py, Target = PyString>,
+        V: IntoPyObject<'py>,
+    {
+        attrs
+            .into_iter()
+            .try_for_each(|(attr_name, value)| self.setattr(attr_name, value))
+    }
+
     fn delattr<N>(&self, attr_name: N) -> PyResult<()>
     where
         N: IntoPyObject<'py, Target = PyString>,
@@ -1280,6 +1545,68 @@ impl<'py> PyAnyMethods<'py> for Bound<'py, PyAny> {
         )
     }
 
+    fn call_method_iter<N, I>(&self, name: N, args: I) -> PyResult<Bound<'py, PyAny>>
+    where
+        N: IntoPyObject<'py, Target = PyString>,
+        I: IntoIterator,
+        I::Item: IntoPyObject<'py>,
+    {
+        /// Adapter to pass an iterator with an exact `size_hint` to `PyTuple::new`.
+        struct ExactSize<I>(I, usize);
+
+        impl<I: Iterator> Iterator for ExactSize<I> {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.1, Some(self.1))
+            }
+        }
+
+        impl<I: Iterator> ExactSizeIterator for ExactSize<I> {}
+
+        let py = self.py();
+        let iter = args.into_iter();
+        let args = match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => PyTuple::new(py, ExactSize(iter, lower))?,
+            _ => {
+                let list = PyList::empty(py);
+                for arg in iter {
+                    list.append(arg)?;
+                }
+                list.to_tuple()
+            }
+        };
+        self.call_method1(name, args)
+    }
+
+    fn call_method_kw_only<'k, N, K, V>(&self, name: N, kwargs: K) -> PyResult<Bound<'py, PyAny>>
+    where
+        N: IntoPyObject<'py, Target = PyString>,
+        K: IntoIterator<Item = (&'k str, V)>,
+        V: IntoPyObject<'py>,
+    {
+        let py = self.py();
+        let dict = PyDict::new(py);
+        for (key, value) in kwargs {
+            let value = value.into_bound_py_any(py).map_err(|err| {
+                let new_err =
+                    PyTypeError::new_err(format!("failed to convert keyword argument '{}'", key));
+                new_err.set_cause(py, Some(err));
+                new_err
+            })?;
+            dict.set_item(key, value)?;
+        }
+        if PyDictMethods::is_empty(&dict) {
+            self.call_method0(name)
+        } else {
+            self.call_method(name, (), Some(&dict))
+        }
+    }
+
     fn is_truthy(&self) -> PyResult<bool> {
         let v = unsafe { ffi::PyObject_IsTrue(self.as_ptr()) };
         err::error_on_minusone(self.py(), v)?;
@@ -1438,6 +1765,24 @@ impl<'py> PyAnyMethods<'py> for Bound<'py, PyAny> {
         std::mem::transmute(self)
     }
 
+    fn as_mapping_opt(&self) -> Option<&Bound<'py, PyMapping>> {
+        if unsafe { ffi::PyMapping_Check(self.as_ptr()) } != 0 {
+            // Safety: `PyMapping` is only used through the abstract mapping API
+            Some(unsafe { self.downcast_unchecked() })
+        } else {
+            None
+        }
+    }
+
+    fn as_sequence_opt(&self) -> Option<&Bound<'py, PySequence>> {
+        if unsafe { ffi::PySequence_Check(self.as_ptr()) } != 0 {
+            // Safety: `PySequence` is only used through the abstract sequence API
+            Some(unsafe { self.downcast_unchecked() })
+        } else {
+            None
+        }
+    }
+
     fn extract<'a, T>(&'a self) -> PyResult<T>
     where
         T: FromPyObjectBound<'a, 'py>,
@@ -1457,6 +1802,20 @@ impl<'py> PyAnyMethods<'py> for Bound<'py, PyAny> {
         }
     }
 
+    fn repr_bytes(&self) -> PyResult<Vec<u8>> {
+        let repr = self.repr()?;
+        let bytes = unsafe {
+            ffi::PyUnicode_AsEncodedString(
+                repr.as_ptr(),
+                ffi::c_str!("utf-8").as_ptr(),
+                ffi::c_str!("surrogatepass").as_ptr(),
+            )
+            .assume_owned_or_err(self.py())?
+            .downcast_into_unchecked::<PyBytes>()
+        };
+        Ok(bytes.as_bytes().to_vec())
+    }
+
     fn str(&self) -> PyResult<Bound<'py, PyString>> {
         unsafe {
             ffi::PyObject_Str(self.as_ptr())
@@ -1576,12 +1935,48 @@ mod tests {
         basic::CompareOp,
         ffi,
         tests::common::generate_unique_module_name,
-        types::{IntoPyDict, PyAny, PyAnyMethods, PyBool, PyInt, PyList, PyModule, PyTypeMethods},
+        types::{
+            IntoPyDict, PyAny, PyAnyMethods, PyBool, PyDict, PyInt, PyList, PyModule,
+            PySequenceMethods, PyTypeMethods,
+        },
         Bound, BoundObject, IntoPyObject, PyTypeInfo, Python,
     };
     use pyo3_ffi::c_str;
     use std::fmt::Debug;
 
+    #[test]
+    fn test_repr_bytes() {
+        Python::with_gil(|py| {
+            let list = vec![1, 2].into_pyobject(py).unwrap();
+            assert_eq!(list.repr_bytes().unwrap(), b"[1, 2]");
+
+            let module = PyModule::from_code(
+                py,
+                c_str!(
+                    r#"
+class Surrogate:
+    def __repr__(self):
+        return "x\ud800y"
+
+class Broken:
+    def __repr__(self):
+        raise ValueError("no repr")
+"#
+                ),
+                c_str!("test.py"),
+                &generate_unique_module_name("test"),
+            )
+            .unwrap();
+
+            let surrogate = module.getattr("Surrogate").unwrap().call0().unwrap();
+            assert_eq!(surrogate.repr_bytes().unwrap(), b"x\xed\xa0\x80y");
+
+            let broken = module.getattr("Broken").unwrap().call0().unwrap();
+            let err = broken.repr_bytes().unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyValueError>(py));
+        });
+    }
+
     #[test]
     fn test_lookup_special() {
         Python::with_gil(|py| {
@@ -1677,6 +2072,94 @@ class NonHeapNonDescriptorInt:
         });
     }
 
+    #[test]
+    fn test_call_method_iter() {
+        Python::with_gil(|py| {
+            let template = crate::types::PyString::new(py, "{}-{}-{}");
+
+            // exact size hint
+            let result = template.call_method_iter("format", [1, 2, 3]).unwrap();
+            assert_eq!(result.extract::<String>().unwrap(), "1-2-3");
+
+            // inexact size hint
+            let result = template
+                .call_method_iter("format", (1..10).filter(|i| i % 3 == 0))
+                .unwrap();
+            assert_eq!(result.extract::<String>().unwrap(), "3-6-9");
+
+            let result = crate::types::PyString::new(py, "abc")
+                .call_method_iter("upper", std::iter::empty::<i32>())
+                .unwrap();
+            assert_eq!(result.extract::<String>().unwrap(), "ABC");
+
+            assert!(template.call_method_iter("format", [1]).is_err());
+        });
+    }
+
+    #[test]
+    fn test_call_method_kw_only() {
+        use crate::exceptions::{PyTypeError, PyValueError};
+        use crate::{PyErr, PyResult};
+
+        Python::with_gil(|py| {
+            let module = PyModule::from_code(
+                py,
+                c_str!(
+                    r#"
+class SimpleClass:
+    def foo(self, *, a, b=2):
+        return a * 10 + b
+
+    def bar(self):
+        return "no arguments"
+"#
+                ),
+                c_str!(file!()),
+                &generate_unique_module_name("test_module"),
+            )
+            .expect("module creation failed");
+
+            let simple_class = module.getattr("SimpleClass").unwrap().call0().unwrap();
+            let result = simple_class
+                .call_method_kw_only("foo", [("a", 4), ("b", 5)])
+                .unwrap();
+            assert_eq!(result.extract::<u32>().unwrap(), 45);
+
+            let result = simple_class.call_method_kw_only("foo", [("a", 4)]).unwrap();
+            assert_eq!(result.extract::<u32>().unwrap(), 42);
+
+            let result = simple_class
+                .call_method_kw_only("bar", std::iter::empty::<(&str, i32)>())
+                .unwrap();
+            assert_eq!(result.extract::<String>().unwrap(), "no arguments");
+
+            // keyword-only arguments cannot be passed positionally
+            assert!(simple_class.call_method1("foo", (4,)).is_err());
+
+            struct Unconvertible;
+
+            impl<'py> IntoPyObject<'py> for Unconvertible {
+                type Target = PyAny;
+                type Output = Bound<'py, PyAny>;
+                type Error = PyErr;
+
+                fn into_pyobject(self, _py: Python<'py>) -> PyResult<Self::Output> {
+                    Err(PyValueError::new_err("cannot convert"))
+                }
+            }
+
+            let err = simple_class
+                .call_method_kw_only("foo", [("a", Unconvertible)])
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+            assert_eq!(
+                err.value(py).to_string(),
+                "failed to convert keyword argument 'a'"
+            );
+            assert!(err.cause(py).unwrap().is_instance_of::<PyValueError>(py));
+        })
+    }
+
     #[test]
     fn test_call_method0() {
         Python::with_gil(|py| {
@@ -1772,6 +2255,122 @@ class SimpleClass:
         })
     }
 
+    #[cfg(feature = "macros")]
+    #[test]
+    #[allow(unknown_lints, non_local_definitions)]
+    fn test_getattr_opt() {
+        use crate::exceptions::{PyAttributeError, PyValueError};
+        use crate::prelude::*;
+
+        #[pyclass(crate = "crate")]
+        struct GetattrSometimes;
+
+        #[pymethods(crate = "crate")]
+        impl GetattrSometimes {
+            fn __getattr__(&self, attr: &str) -> PyResult<Option<i32>> {
+                match attr {
+                    "present" => Ok(Some(1)),
+                    "none" => Ok(None),
+                    "missing" => Err(PyAttributeError::new_err(attr.to_owned())),
+                    _ => Err(PyValueError::new_err(attr.to_owned())),
+                }
+            }
+        }
+
+        Python::with_gil(|py| {
+            let obj = Bound::new(py, GetattrSometimes).unwrap().into_any();
+
+            let present = obj.getattr_opt("present").unwrap().unwrap();
+            assert_eq!(present.extract::<i32>().unwrap(), 1);
+            assert!(obj.getattr_opt("none").unwrap().unwrap().is_none());
+            assert!(obj.getattr_opt("missing").unwrap().is_none());
+            assert!(obj
+                .getattr_opt("other")
+                .unwrap_err()
+                .is_instance_of::<PyValueError>(py));
+            assert!(PyErr::take(py).is_none());
+
+            let x = 5i32.into_pyobject(py).unwrap();
+            assert!(x.getattr_opt("to_bytes").unwrap().is_some());
+            assert!(x.getattr_opt("bbbbbbytes").unwrap().is_none());
+        })
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn test_try_iter_errors() {
+        use crate::exceptions::{PyTypeError, PyValueError};
+        use crate::prelude::*;
+
+        #[pyclass(crate = "crate")]
+        struct IterFail;
+
+        #[pymethods(crate = "crate")]
+        impl IterFail {
+            fn __iter__(&self) -> PyResult<PyObject> {
+                Err(PyValueError::new_err("no iterating"))
+            }
+        }
+
+        Python::with_gil(|py| {
+            let list = PyList::new(py, [1, 2, 3]).unwrap();
+            let items: Vec<i32> = list
+                .try_iter()
+                .unwrap()
+                .map(|item| item.unwrap().extract().unwrap())
+                .collect();
+            assert_eq!(items, [1, 2, 3]);
+
+            let int = 5i32.into_pyobject(py).unwrap();
+            assert!(int
+                .try_iter()
+                .unwrap_err()
+                .is_instance_of::<PyTypeError>(py));
+
+            let obj = Bound::new(py, IterFail).unwrap();
+            let err = obj.try_iter().unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert_eq!(err.to_string(), "ValueError: no iterating");
+        })
+    }
+
+    #[test]
+    fn test_setattr_many() {
+        Python::with_gil(|py| {
+            let module = PyModule::from_code(
+                py,
+                ffi::c_str!(
+                    r#"
+class SimpleClass:
+    @property
+    def read_only(self):
+        return 0
+"#
+                ),
+                ffi::c_str!(file!()),
+                &generate_unique_module_name("test_module"),
+            )
+            .unwrap();
+            let obj = module.getattr("SimpleClass").unwrap().call0().unwrap();
+
+            obj.setattr_many([("a", 1), ("b", 2)]).unwrap();
+            assert_eq!(obj.getattr("a").unwrap().extract::<i32>().unwrap(), 1);
+            assert_eq!(obj.getattr("b").unwrap().extract::<i32>().unwrap(), 2);
+
+            // attributes set before the failure are kept
+            let err = obj
+                .setattr_many([("c", 3), ("read_only", 4), ("d", 5)])
+                .unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyAttributeError>(py));
+            assert_eq!(obj.getattr("c").unwrap().extract::<i32>().unwrap(), 3);
+            assert_eq!(
+                obj.getattr("read_only").unwrap().extract::<i32>().unwrap(),
+                0
+            );
+            assert!(!obj.hasattr("d").unwrap());
+        })
+    }
+
     #[test]
     fn test_nan_eq() {
         Python::with_gil(|py| {
@@ -1815,6 +2414,23 @@ class SimpleClass:
         });
     }
 
+    #[test]
+    fn test_any_downcast_into_exact() {
+        Python::with_gil(|py| {
+            let x = 5i32.into_pyobject(py).unwrap().into_any();
+            let x = x.downcast_into_exact::<PyInt>().unwrap();
+            assert_eq!(x.extract::<i32>().unwrap(), 5);
+
+            let t = PyBool::new(py, true).to_owned().into_any();
+            let err = t.downcast_into_exact::<PyInt>().unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "'bool' object cannot be converted to 'PyInt'"
+            );
+            assert!(err.into_inner().downcast_into_exact::<PyBool>().is_ok());
+        });
+    }
+
     #[test]
     fn test_any_is_exact_instance() {
         Python::with_gil(|py| {
@@ -2001,6 +2617,31 @@ class SimpleClass:
         });
     }
 
+    #[test]
+    fn test_as_mapping_opt_and_as_sequence_opt() {
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py).into_any();
+            assert!(dict.as_mapping_opt().is_some());
+            assert!(dict.as_sequence_opt().is_none());
+
+            // lists implement both `mp_subscript` and `sq_item`
+            let list = PyList::new(py, [1, 2, 3]).unwrap().into_any();
+            assert!(list.as_mapping_opt().is_some());
+            let seq = list.as_sequence_opt().unwrap();
+            assert_eq!(seq.len().unwrap(), 3);
+
+            let object = py
+                .import("builtins")
+                .unwrap()
+                .getattr("object")
+                .unwrap()
+                .call0()
+                .unwrap();
+            assert!(object.as_mapping_opt().is_none());
+            assert!(object.as_sequence_opt().is_none());
+        });
+    }
+
     #[cfg(feature = "macros")]
     #[test]
     #[allow(unknown_lints, non_local_definitions)]
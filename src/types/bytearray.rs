@@ -4,6 +4,7 @@ use crate::instance::{Borrowed, Bound};
 use crate::py_result_ext::PyResultExt;
 use crate::types::any::PyAnyMethods;
 use crate::{ffi, PyAny, Python};
+use std::ops::{Deref, DerefMut};
 use std::slice;
 
 /// Represents a Python `bytearray`.
@@ -243,6 +244,36 @@ pub trait PyByteArrayMethods<'py>: crate::sealed::Sealed {
     #[allow(clippy::mut_from_ref)]
     unsafe fn as_bytes_mut(&self) -> &mut [u8];
 
+    /// Returns a guard which dereferences to a mutable slice of the `ByteArray`'s entire buffer.
+    ///
+    /// The guard mutably borrows this handle to the bytearray, so methods such as
+    /// [`PyByteArrayMethods::resize`] cannot be called through it while the slice is alive. This
+    /// makes the aliasing explicit, unlike [`PyByteArrayMethods::as_bytes_mut`].
+    ///
+    /// # Safety
+    ///
+    /// The guard cannot prevent the bytearray from being resized or otherwise accessed through
+    /// other references to the same object, including from Python code. The caller must ensure
+    /// that no such access happens while the guard is alive; see [`PyByteArrayMethods::as_bytes`]
+    /// for the ways in which this can go wrong.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use pyo3::prelude::*;
+    /// # use pyo3::types::PyByteArray;
+    /// # Python::with_gil(|py| {
+    /// let mut bytearray = PyByteArray::new(py, b"Hello World.");
+    /// {
+    ///     let mut guard = unsafe { bytearray.as_bytes_mut_guard() };
+    ///     guard[11] = b'!';
+    ///     // bytearray.resize(0) would not compile here
+    /// }
+    /// pyo3::py_run!(py, bytearray, "assert bytearray == b'Hello World!'");
+    /// # });
+    /// ```
+    unsafe fn as_bytes_mut_guard(&mut self) -> PyByteArrayMutGuard<'_>;
+
     /// Copies the contents of the bytearray to a Rust vector.
     ///
     /// # Examples
@@ -294,6 +325,12 @@ impl<'py> PyByteArrayMethods<'py> for Bound<'py, PyByteArray> {
         self.as_borrowed().as_bytes_mut()
     }
 
+    unsafe fn as_bytes_mut_guard(&mut self) -> PyByteArrayMutGuard<'_> {
+        PyByteArrayMutGuard {
+            bytes: self.as_borrowed().as_bytes_mut(),
+        }
+    }
+
     fn to_vec(&self) -> Vec<u8> {
         unsafe { self.as_bytes() }.to_vec()
     }
@@ -310,6 +347,30 @@ impl<'py> PyByteArrayMethods<'py> for Bound<'py, PyByteArray> {
     }
 }
 
+/// A mutable view of a [`PyByteArray`]'s buffer, created by
+/// [`PyByteArrayMethods::as_bytes_mut_guard`].
+///
+/// The guard holds a mutable borrow of the `Bound<PyByteArray>` it was created from, so the
+/// bytearray cannot be resized through that handle while the guard is alive.
+#[derive(Debug)]
+pub struct PyByteArrayMutGuard<'a> {
+    bytes: &'a mut [u8],
+}
+
+impl Deref for PyByteArrayMutGuard<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.bytes
+    }
+}
+
+impl DerefMut for PyByteArrayMutGuard<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.bytes
+    }
+}
+
 impl<'a> Borrowed<'a, '_, PyByteArray> {
     fn data(&self) -> *mut u8 {
         unsafe { ffi::PyByteArray_AsString(self.as_ptr()).cast() }
@@ -378,6 +439,24 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_as_bytes_mut_guard() {
+        Python::with_gil(|py| {
+            let src = b"Hello Python";
+            let mut bytearray = PyByteArray::new(py, src);
+            let data = bytearray.data();
+
+            {
+                let mut guard = unsafe { bytearray.as_bytes_mut_guard() };
+                assert_eq!(&src[..], &*guard);
+                assert_eq!(data, guard.as_mut_ptr());
+                guard[0..5].copy_from_slice(b"Hi...");
+            }
+
+            assert_eq!(bytearray.str().unwrap(), "bytearray(b'Hi... Python')");
+        });
+    }
+
     #[test]
     fn test_to_vec() {
         Python::with_gil(|py| {
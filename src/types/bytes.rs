@@ -1,7 +1,9 @@
+use crate::exceptions::PyValueError;
 use crate::ffi_ptr_ext::FfiPtrExt;
 use crate::instance::{Borrowed, Bound};
 use crate::types::any::PyAnyMethods;
 use crate::{ffi, Py, PyAny, PyResult, Python};
+use std::mem::MaybeUninit;
 use std::ops::Index;
 use std::slice::SliceIndex;
 use std::str;
@@ -75,9 +77,12 @@ impl PyBytes {
     /// Before calling `init` the bytes' contents are zero-initialised.
     /// * If Python raises a MemoryError on the allocation, `new_with` will return
     ///   it inside `Err`.
-    /// * If `init` returns `Err(e)`, `new_with` will return `Err(e)`.
+    /// * If `init` returns `Err(e)`, `new_with` will return `Err(e)`. The partially initialised
+    ///   `bytes` object is freed, so it can never be observed from Python.
     /// * If `init` returns `Ok(())`, `new_with` will return `Ok(&PyBytes)`.
     ///
+    /// See [`PyBytes::new_with_uninit`] to skip the zero-initialisation.
+    ///
     /// # Examples
     ///
     /// ```
@@ -114,6 +119,57 @@ impl PyBytes {
         }
     }
 
+    /// Creates a new Python `bytes` object with an `init` closure to write its contents, without
+    /// zero-initialising them first.
+    ///
+    /// This behaves like [`PyBytes::new_with`], but avoids the cost of zeroing the buffer when
+    /// `init` overwrites all of it anyway, e.g. in serializers. If `init` returns `Err(e)`, the
+    /// `bytes` object is freed and `Err(e)` is returned.
+    ///
+    /// # Safety
+    ///
+    /// If `init` returns `Ok(())`, it must have initialised every byte of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyo3::{prelude::*, types::PyBytes};
+    /// use std::mem::MaybeUninit;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| -> PyResult<()> {
+    ///     // Safety: the closure initialises every byte
+    ///     let py_bytes = unsafe {
+    ///         PyBytes::new_with_uninit(py, 4, |bytes: &mut [MaybeUninit<u8>]| {
+    ///             for (i, byte) in bytes.iter_mut().enumerate() {
+    ///                 byte.write(b'a' + i as u8);
+    ///             }
+    ///             Ok(())
+    ///         })?
+    ///     };
+    ///     assert_eq!(py_bytes.as_bytes(), b"abcd");
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    #[inline]
+    pub unsafe fn new_with_uninit<F>(
+        py: Python<'_>,
+        len: usize,
+        init: F,
+    ) -> PyResult<Bound<'_, PyBytes>>
+    where
+        F: FnOnce(&mut [MaybeUninit<u8>]) -> PyResult<()>,
+    {
+        let pyptr = ffi::PyBytes_FromStringAndSize(std::ptr::null(), len as ffi::Py_ssize_t);
+        // Check for an allocation error and return it
+        let pybytes = pyptr.assume_owned_or_err(py)?.downcast_into_unchecked();
+        let buffer: *mut MaybeUninit<u8> = ffi::PyBytes_AsString(pyptr).cast();
+        debug_assert!(!buffer.is_null());
+        // If init returns an Err, pybytes will automatically deallocate the buffer
+        init(std::slice::from_raw_parts_mut(buffer, len)).map(|_| pybytes)
+    }
+
     /// Deprecated name for [`PyBytes::new_with`].
     #[deprecated(since = "0.23.0", note = "renamed to `PyBytes::new_with`")]
     #[inline]
@@ -153,6 +209,27 @@ impl PyBytes {
     pub unsafe fn bound_from_ptr(py: Python<'_>, ptr: *const u8, len: usize) -> Bound<'_, PyBytes> {
         Self::from_ptr(py, ptr, len)
     }
+
+    /// Builds a translation table for [`PyBytesMethods::translate`] which maps each byte in
+    /// `from` to the byte at the same position in `to`, leaving all other bytes unchanged.
+    ///
+    /// This is equivalent to the Python expression `bytes.maketrans(from, to)`. Raises
+    /// `ValueError` if `from` and `to` differ in length.
+    pub fn maketrans(from: &[u8], to: &[u8]) -> PyResult<[u8; 256]> {
+        if from.len() != to.len() {
+            return Err(PyValueError::new_err(
+                "maketrans arguments must have same length",
+            ));
+        }
+        let mut table = [0u8; 256];
+        for (i, entry) in table.iter_mut().enumerate() {
+            *entry = i as u8;
+        }
+        for (&from, &to) in from.iter().zip(to) {
+            table[usize::from(from)] = to;
+        }
+        Ok(table)
+    }
 }
 
 /// Implementation of functionality for [`PyBytes`].
@@ -164,6 +241,29 @@ impl PyBytes {
 pub trait PyBytesMethods<'py>: crate::sealed::Sealed {
     /// Gets the Python string as a byte slice.
     fn as_bytes(&self) -> &[u8];
+
+    /// Returns a copy of these bytes with each byte mapped through `table`, after removing all
+    /// bytes contained in `delete`.
+    ///
+    /// This is equivalent to the Python expression `self.translate(table, delete)`. A table can
+    /// be built with [`PyBytes::maketrans`], and `delete` may be empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyBytes;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let bytes = PyBytes::new(py, b"Hello, World!");
+    ///     let table = PyBytes::maketrans(b"HW", b"hw")?;
+    ///     assert_eq!(bytes.translate(&table, b",!")?.as_bytes(), b"hello world");
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    fn translate(&self, table: &[u8; 256], delete: &[u8]) -> PyResult<Bound<'py, PyBytes>>;
 }
 
 impl<'py> PyBytesMethods<'py> for Bound<'py, PyBytes> {
@@ -171,6 +271,16 @@ impl<'py> PyBytesMethods<'py> for Bound<'py, PyBytes> {
     fn as_bytes(&self) -> &[u8] {
         self.as_borrowed().as_bytes()
     }
+
+    fn translate(&self, table: &[u8; 256], delete: &[u8]) -> PyResult<Bound<'py, PyBytes>> {
+        let py = self.py();
+        self.call_method1(
+            intern!(py, "translate"),
+            (PyBytes::new(py, table), PyBytes::new(py, delete)),
+        )?
+        .downcast_into()
+        .map_err(Into::into)
+    }
 }
 
 impl<'a> Borrowed<'a, '_, PyBytes> {
@@ -365,6 +475,65 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_bytes_new_with_uninit() {
+        use crate::exceptions::PyValueError;
+        use std::mem::MaybeUninit;
+        Python::with_gil(|py| {
+            let py_bytes = unsafe {
+                PyBytes::new_with_uninit(py, 10, |b: &mut [MaybeUninit<u8>]| {
+                    for (dst, src) in b.iter_mut().zip(b"Hello Rust") {
+                        dst.write(*src);
+                    }
+                    Ok(())
+                })
+            }
+            .unwrap();
+            assert_eq!(py_bytes.as_bytes(), b"Hello Rust");
+
+            let err = unsafe {
+                PyBytes::new_with_uninit(py, 10, |_b: &mut [MaybeUninit<u8>]| {
+                    Err(PyValueError::new_err("Hello Crustaceans!"))
+                })
+            }
+            .unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn test_bytes_translate() {
+        Python::with_gil(|py| {
+            let bytes = PyBytes::new(py, b"abc-ABC_123");
+
+            let table = PyBytes::maketrans(b"ABC", b"abc").unwrap();
+            assert_eq!(
+                bytes.translate(&table, b"").unwrap().as_bytes(),
+                b"abc-abc_123"
+            );
+
+            // deleted bytes are removed before the translation is applied
+            let table = PyBytes::maketrans(b"-_", b"__").unwrap();
+            assert_eq!(
+                bytes.translate(&table, b"_3").unwrap().as_bytes(),
+                b"abc_ABC12"
+            );
+
+            let mut identity = [0u8; 256];
+            for (i, entry) in identity.iter_mut().enumerate() {
+                *entry = i as u8;
+            }
+            assert_eq!(PyBytes::maketrans(b"", b"").unwrap(), identity);
+            assert_eq!(
+                bytes.translate(&identity, b"").unwrap().as_bytes(),
+                bytes.as_bytes()
+            );
+
+            let err = PyBytes::maketrans(b"ab", b"a").unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
     #[test]
     fn test_comparisons() {
         Python::with_gil(|py| {
@@ -1,10 +1,16 @@
+use crate::err::PyResult;
 use crate::ffi;
-use crate::PyAny;
+use crate::types::{any::PyAnyMethods, PyString, PyTuple};
+use crate::{Bound, PyAny};
+use std::os::raw::c_int;
 
 /// Represents a Python code object.
 ///
 /// Values of this type are accessed via PyO3's smart pointers, e.g. as
 /// [`Py<PyCode>`][crate::Py] or [`Bound<'py, PyCode>`][crate::Bound].
+///
+/// For APIs available on code objects, see the [`PyCodeMethods`] trait which is implemented for
+/// [`Bound<'py, PyCode>`][Bound].
 #[repr(transparent)]
 pub struct PyCode(PyAny);
 
@@ -14,10 +20,66 @@ pyobject_native_type_core!(
     #checkfunction=ffi::PyCode_Check
 );
 
+/// Implementation of functionality for [`PyCode`].
+///
+/// These methods are defined for the `Bound<'py, PyCode>` smart pointer, so to use method call
+/// syntax these methods are separated into a trait, because stable Rust does not yet support
+/// `arbitrary_self_types`.
+///
+/// The layout of code objects changes between Python versions, so these methods read the
+/// corresponding Python attributes rather than the fields of `ffi::PyCodeObject`.
+#[doc(alias = "PyCode")]
+pub trait PyCodeMethods<'py>: crate::sealed::Sealed {
+    /// Returns the number of positional arguments, including positional-only arguments and
+    /// arguments with default values (`co_argcount`).
+    fn co_argcount(&self) -> PyResult<usize>;
+
+    /// Returns the names of the local variables, starting with the argument names
+    /// (`co_varnames`).
+    fn co_varnames(&self) -> PyResult<Bound<'py, PyTuple>>;
+
+    /// Returns the flags of the code object (`co_flags`), e.g. [`ffi::CO_GENERATOR`].
+    fn co_flags(&self) -> PyResult<c_int>;
+
+    /// Returns the name of the file from which the code was compiled (`co_filename`).
+    fn co_filename(&self) -> PyResult<Bound<'py, PyString>>;
+
+    /// Returns the name of the function, class or module the code object belongs to (`co_name`).
+    fn co_name(&self) -> PyResult<Bound<'py, PyString>>;
+}
+
+impl<'py> PyCodeMethods<'py> for Bound<'py, PyCode> {
+    fn co_argcount(&self) -> PyResult<usize> {
+        self.getattr(intern!(self.py(), "co_argcount"))?.extract()
+    }
+
+    fn co_varnames(&self) -> PyResult<Bound<'py, PyTuple>> {
+        self.getattr(intern!(self.py(), "co_varnames"))?
+            .downcast_into()
+            .map_err(Into::into)
+    }
+
+    fn co_flags(&self) -> PyResult<c_int> {
+        self.getattr(intern!(self.py(), "co_flags"))?.extract()
+    }
+
+    fn co_filename(&self) -> PyResult<Bound<'py, PyString>> {
+        self.getattr(intern!(self.py(), "co_filename"))?
+            .downcast_into()
+            .map_err(Into::into)
+    }
+
+    fn co_name(&self) -> PyResult<Bound<'py, PyString>> {
+        self.getattr(intern!(self.py(), "co_name"))?
+            .downcast_into()
+            .map_err(Into::into)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::PyTypeMethods;
+    use crate::types::{PyDictMethods, PyStringMethods, PyTypeMethods};
     use crate::{PyTypeInfo, Python};
 
     #[test]
@@ -26,4 +88,37 @@ mod tests {
             assert_eq!(PyCode::type_object(py).name().unwrap(), "code");
         })
     }
+
+    #[test]
+    fn test_code_attributes() {
+        Python::with_gil(|py| {
+            let locals = crate::types::PyDict::new(py);
+            py.run(
+                ffi::c_str!("def f(a, b, /, c=1, *, d):\n    e = a\n    yield e"),
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+            let code = locals
+                .get_item("f")
+                .unwrap()
+                .unwrap()
+                .getattr("__code__")
+                .unwrap()
+                .downcast_into::<PyCode>()
+                .unwrap();
+
+            assert_eq!(code.co_argcount().unwrap(), 3);
+            assert_eq!(
+                code.co_varnames()
+                    .unwrap()
+                    .extract::<Vec<String>>()
+                    .unwrap(),
+                ["a", "b", "c", "d", "e"]
+            );
+            assert_ne!(code.co_flags().unwrap() & ffi::CO_GENERATOR, 0);
+            assert_eq!(code.co_filename().unwrap().to_cow().unwrap(), "<string>");
+            assert_eq!(code.co_name().unwrap().to_cow().unwrap(), "f");
+        })
+    }
 }
@@ -1,10 +1,11 @@
 use crate::err::{self, PyErr, PyResult};
+use crate::exceptions::{PyKeyError, PyTypeError, PyValueError};
 use crate::ffi::Py_ssize_t;
 use crate::ffi_ptr_ext::FfiPtrExt;
 use crate::instance::{Borrowed, Bound};
 use crate::py_result_ext::PyResultExt;
-use crate::types::{PyAny, PyAnyMethods, PyList, PyMapping};
-use crate::{ffi, BoundObject, IntoPyObject, IntoPyObjectExt, Python};
+use crate::types::{PyAny, PyAnyMethods, PyList, PyListMethods, PyMapping};
+use crate::{ffi, BoundObject, FromPyObject, IntoPyObject, IntoPyObjectExt, Python};
 
 /// Represents a Python `dict`.
 ///
@@ -139,6 +140,15 @@ pub trait PyDictMethods<'py>: crate::sealed::Sealed {
     where
         K: IntoPyObject<'py>;
 
+    /// Gets an item from the dictionary, or `default` if the item is not present.
+    ///
+    /// This is equivalent to the Python expression `self.get(key, default)`. An error raised by
+    /// the lookup itself, e.g. for an unhashable key, is returned instead of the default.
+    fn get_item_with_default<K, D>(&self, key: K, default: D) -> PyResult<Bound<'py, PyAny>>
+    where
+        K: IntoPyObject<'py>,
+        D: IntoPyObject<'py>;
+
     /// Sets an item value.
     ///
     /// This is equivalent to the Python statement `self[key] = value`.
@@ -154,6 +164,12 @@ pub trait PyDictMethods<'py>: crate::sealed::Sealed {
     where
         K: IntoPyObject<'py>;
 
+    /// Removes and returns the most recently inserted key-value pair.
+    ///
+    /// This is equivalent to the Python expression `self.popitem()`, except that `None` is
+    /// returned if the dictionary is empty instead of raising `KeyError`.
+    fn popitem(&self) -> PyResult<Option<(Bound<'py, PyAny>, Bound<'py, PyAny>)>>;
+
     /// Returns a list of dict keys.
     ///
     /// This is equivalent to the Python expression `list(dict.keys())`.
@@ -164,6 +180,14 @@ pub trait PyDictMethods<'py>: crate::sealed::Sealed {
     /// This is equivalent to the Python expression `list(dict.values())`.
     fn values(&self) -> Bound<'py, PyList>;
 
+    /// Extracts the dict values into a `Vec`, in insertion order.
+    ///
+    /// This avoids creating the intermediate list of [`values`](PyDictMethods::values). If any
+    /// value fails to extract, the error is returned.
+    fn values_as<V>(&self) -> PyResult<Vec<V>>
+    where
+        V: FromPyObject<'py>;
+
     /// Returns a list of dict items.
     ///
     /// This is equivalent to the Python expression `list(dict.items())`.
@@ -213,6 +237,20 @@ pub trait PyDictMethods<'py>: crate::sealed::Sealed {
     /// This method uses [`PyDict_Merge`](https://docs.python.org/3/c-api/dict.html#c.PyDict_Merge) internally,
     /// so should have the same performance as `update`.
     fn update_if_missing(&self, other: &Bound<'_, PyMapping>) -> PyResult<()>;
+
+    /// Compares the contents of this dictionary with another.
+    ///
+    /// Returns a tuple of three lists: the keys only present in `self`, the keys only present in
+    /// `other`, and the keys present in both whose values differ.
+    ///
+    /// Values are compared using Python equality, as by
+    /// [`PyObject_RichCompareBool`](https://docs.python.org/3/c-api/object.html#c.PyObject_RichCompareBool).
+    /// If the comparison raises a `TypeError` or `ValueError`, the values are considered to differ;
+    /// any other exception is returned as an error.
+    fn diff(
+        &self,
+        other: &Bound<'_, PyDict>,
+    ) -> PyResult<(Bound<'py, PyList>, Bound<'py, PyList>, Bound<'py, PyList>)>;
 }
 
 impl<'py> PyDictMethods<'py> for Bound<'py, PyDict> {
@@ -285,6 +323,17 @@ impl<'py> PyDictMethods<'py> for Bound<'py, PyDict> {
         )
     }
 
+    fn get_item_with_default<K, D>(&self, key: K, default: D) -> PyResult<Bound<'py, PyAny>>
+    where
+        K: IntoPyObject<'py>,
+        D: IntoPyObject<'py>,
+    {
+        match self.get_item(key)? {
+            Some(value) => Ok(value),
+            None => default.into_bound_py_any(self.py()),
+        }
+    }
+
     fn set_item<K, V>(&self, key: K, value: V) -> PyResult<()>
     where
         K: IntoPyObject<'py>,
@@ -325,6 +374,15 @@ impl<'py> PyDictMethods<'py> for Bound<'py, PyDict> {
         )
     }
 
+    fn popitem(&self) -> PyResult<Option<(Bound<'py, PyAny>, Bound<'py, PyAny>)>> {
+        let py = self.py();
+        match self.call_method0(intern!(py, "popitem")) {
+            Ok(item) => item.extract().map(Some),
+            Err(err) if err.is_instance_of::<PyKeyError>(py) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     fn keys(&self) -> Bound<'py, PyList> {
         unsafe {
             ffi::PyDict_Keys(self.as_ptr())
@@ -341,6 +399,13 @@ impl<'py> PyDictMethods<'py> for Bound<'py, PyDict> {
         }
     }
 
+    fn values_as<V>(&self) -> PyResult<Vec<V>>
+    where
+        V: FromPyObject<'py>,
+    {
+        self.iter().map(|(_, value)| value.extract()).collect()
+    }
+
     fn items(&self) -> Bound<'py, PyList> {
         unsafe {
             ffi::PyDict_Items(self.as_ptr())
@@ -391,6 +456,51 @@ impl<'py> PyDictMethods<'py> for Bound<'py, PyDict> {
             ffi::PyDict_Merge(self.as_ptr(), other.as_ptr(), 0)
         })
     }
+
+    fn diff(
+        &self,
+        other: &Bound<'_, PyDict>,
+    ) -> PyResult<(Bound<'py, PyList>, Bound<'py, PyList>, Bound<'py, PyList>)> {
+        let py = self.py();
+        let only_self = PyList::empty(py);
+        let only_other = PyList::empty(py);
+        let changed = PyList::empty(py);
+
+        for (key, value) in self {
+            match other.get_item(&key)? {
+                None => only_self.append(key)?,
+                Some(other_value) => {
+                    let equal = unsafe {
+                        ffi::PyObject_RichCompareBool(
+                            value.as_ptr(),
+                            other_value.as_ptr(),
+                            ffi::Py_EQ,
+                        )
+                    };
+                    if equal == -1 {
+                        // incomparable values are reported as differing
+                        let err = PyErr::fetch(py);
+                        if !err.is_instance_of::<PyTypeError>(py)
+                            && !err.is_instance_of::<PyValueError>(py)
+                        {
+                            return Err(err);
+                        }
+                    }
+                    if equal != 1 {
+                        changed.append(key)?;
+                    }
+                }
+            }
+        }
+
+        for key in other.keys() {
+            if !self.contains(&key)? {
+                only_other.append(key)?;
+            }
+        }
+
+        Ok((only_self, only_other, changed))
+    }
 }
 
 impl<'a, 'py> Borrowed<'a, 'py, PyDict> {
@@ -995,6 +1105,23 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_get_item_with_default() {
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item(7i32, 32i32).unwrap();
+            let value = dict.get_item_with_default(7i32, 0i32).unwrap();
+            assert_eq!(value.extract::<i32>().unwrap(), 32);
+            let value = dict.get_item_with_default(8i32, 0i32).unwrap();
+            assert_eq!(value.extract::<i32>().unwrap(), 0);
+
+            // errors from the lookup are not masked by the default
+            let unhashable = PyList::empty(py);
+            let err = dict.get_item_with_default(unhashable, 0i32).unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyTypeError>(py));
+        });
+    }
+
     #[test]
     fn test_set_item() {
         Python::with_gil(|py| {
@@ -1062,6 +1189,21 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_popitem() {
+        Python::with_gil(|py| {
+            let dict = [(1, "a"), (2, "b"), (3, "c")].into_py_dict(py).unwrap();
+
+            for (key, value) in [(3, "c"), (2, "b"), (1, "a")] {
+                let (k, v) = dict.popitem().unwrap().unwrap();
+                assert_eq!(k.extract::<i32>().unwrap(), key);
+                assert_eq!(v.extract::<String>().unwrap(), value);
+            }
+            assert!(dict.is_empty());
+            assert!(dict.popitem().unwrap().is_none());
+        });
+    }
+
     #[test]
     fn test_del_item_does_not_update_original_object() {
         Python::with_gil(|py| {
@@ -1128,6 +1270,19 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_values_as() {
+        Python::with_gil(|py| {
+            let dict = [("c", 3), ("a", 1), ("b", 2)].into_py_dict(py).unwrap();
+            assert_eq!(dict.values_as::<i32>().unwrap(), [3, 1, 2]);
+            assert!(PyDict::new(py).values_as::<i32>().unwrap().is_empty());
+
+            dict.set_item("d", "four").unwrap();
+            let err = dict.values_as::<i32>().unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyTypeError>(py));
+        });
+    }
+
     #[test]
     fn test_iter() {
         Python::with_gil(|py| {
@@ -1571,6 +1726,103 @@ mod tests {
         })
     }
 
+    #[test]
+    fn dict_update_if_missing_from_non_dict_mapping() {
+        Python::with_gil(|py| {
+            let dict = [("a", 1), ("b", 2)].into_py_dict(py).unwrap();
+            let defaults = [("b", 4), ("c", 5)].into_py_dict(py).unwrap();
+            let proxy = py
+                .import("types")
+                .unwrap()
+                .getattr("MappingProxyType")
+                .unwrap()
+                .call1((defaults,))
+                .unwrap();
+            dict.update_if_missing(proxy.downcast::<PyMapping>().unwrap())
+                .unwrap();
+
+            assert_eq!(dict.extract::<BTreeMap<String, i32>>().unwrap(), {
+                let mut expected = BTreeMap::new();
+                expected.insert("a".to_owned(), 1);
+                expected.insert("b".to_owned(), 2);
+                expected.insert("c".to_owned(), 5);
+                expected
+            });
+        })
+    }
+
+    #[test]
+    fn dict_diff() {
+        Python::with_gil(|py| {
+            let dict = [("a", 1), ("b", 2), ("c", 3)].into_py_dict(py).unwrap();
+            let other = [("b", 2), ("c", 5), ("d", 6)].into_py_dict(py).unwrap();
+            let (only_self, only_other, changed) = dict.diff(&other).unwrap();
+            assert_eq!(only_self.extract::<Vec<String>>().unwrap(), ["a"]);
+            assert_eq!(only_other.extract::<Vec<String>>().unwrap(), ["d"]);
+            assert_eq!(changed.extract::<Vec<String>>().unwrap(), ["c"]);
+
+            let (only_self, only_other, changed) = other.diff(&dict).unwrap();
+            assert_eq!(only_self.extract::<Vec<String>>().unwrap(), ["d"]);
+            assert_eq!(only_other.extract::<Vec<String>>().unwrap(), ["a"]);
+            assert_eq!(changed.extract::<Vec<String>>().unwrap(), ["c"]);
+
+            let empty = PyDict::new(py);
+            let (only_self, only_other, changed) = empty.diff(&PyDict::new(py)).unwrap();
+            assert!(only_self.is_empty());
+            assert!(only_other.is_empty());
+            assert!(changed.is_empty());
+        })
+    }
+
+    #[test]
+    fn dict_diff_incomparable_values() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new(py);
+            py.run(
+                ffi::c_str!(
+                    "class Incomparable:\n    def __eq__(self, other):\n        raise TypeError('cannot compare')\n\na = {'x': Incomparable(), 'y': 1}\nb = {'x': Incomparable(), 'y': 1}"
+                ),
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+            let a = locals.get_item("a").unwrap().unwrap();
+            let b = locals.get_item("b").unwrap().unwrap();
+            let (only_self, only_other, changed) = a
+                .downcast::<PyDict>()
+                .unwrap()
+                .diff(b.downcast::<PyDict>().unwrap())
+                .unwrap();
+            assert!(only_self.is_empty());
+            assert!(only_other.is_empty());
+            assert_eq!(changed.extract::<Vec<String>>().unwrap(), ["x"]);
+            assert!(!PyErr::occurred(py));
+        })
+    }
+
+    #[test]
+    fn dict_diff_propagates_other_errors() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new(py);
+            py.run(
+                ffi::c_str!(
+                    "class Interrupting:\n    def __eq__(self, other):\n        raise KeyboardInterrupt\n\na = {'x': Interrupting()}\nb = {'x': Interrupting()}"
+                ),
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+            let a = locals.get_item("a").unwrap().unwrap();
+            let b = locals.get_item("b").unwrap().unwrap();
+            let err = a
+                .downcast::<PyDict>()
+                .unwrap()
+                .diff(b.downcast::<PyDict>().unwrap())
+                .unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyKeyboardInterrupt>(py));
+        })
+    }
+
     #[test]
     fn test_iter_all() {
         Python::with_gil(|py| {
@@ -1,5 +1,6 @@
 use super::any::PyAnyMethods;
 use crate::conversion::IntoPyObject;
+use crate::exceptions::PyValueError;
 #[cfg(feature = "experimental-inspect")]
 use crate::inspect::types::TypeInfo;
 use crate::{
@@ -292,6 +293,59 @@ macro_rules! impl_partial_eq_for_float {
 impl_partial_eq_for_float!(f64);
 impl_partial_eq_for_float!(f32);
 
+/// A finite `f64`, extracted from a Python `float` or any object implementing `__float__`.
+///
+/// Extraction fails with a `ValueError` if the value is NaN or infinite, which saves checking
+/// [`f64::is_finite`] after extracting values such as probabilities or coordinates.
+///
+/// # Example
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::types::FiniteF64;
+///
+/// #[pyfunction]
+/// fn halve(x: FiniteF64) -> f64 {
+///     x.0 / 2.0
+/// }
+///
+/// Python::with_gil(|py| {
+///     let halve = wrap_pyfunction!(halve, py).unwrap();
+///     assert_eq!(halve.call1((3.0,)).unwrap().extract::<f64>().unwrap(), 1.5);
+///     let err = halve.call1((f64::NAN,)).unwrap_err();
+///     assert!(err.to_string().contains("expected a finite float, got nan"));
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct FiniteF64(pub f64);
+
+impl<'py> FromPyObject<'py> for FiniteF64 {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let value: f64 = obj.extract()?;
+        if value.is_finite() {
+            Ok(FiniteF64(value))
+        } else {
+            // Use the spelling of Python's `repr`
+            let repr = if value.is_nan() {
+                "nan"
+            } else if value > 0.0 {
+                "inf"
+            } else {
+                "-inf"
+            };
+            Err(PyValueError::new_err(format!(
+                "expected a finite float, got {}",
+                repr
+            )))
+        }
+    }
+
+    #[cfg(feature = "experimental-inspect")]
+    fn type_input() -> TypeInfo {
+        f64::type_input()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -393,4 +447,50 @@ mod tests {
             assert_eq!(&f_32, py_f32_borrowed);
         });
     }
+
+    #[test]
+    fn test_extract_finite_f64() {
+        use super::FiniteF64;
+        use crate::exceptions::PyValueError;
+
+        Python::with_gil(|py| {
+            let v = 1.5f64.into_pyobject(py).unwrap();
+            assert_eq!(v.extract::<FiniteF64>().unwrap(), FiniteF64(1.5));
+
+            // integers and other objects with `__float__` are accepted
+            let v = 3i32.into_pyobject(py).unwrap();
+            assert_eq!(v.extract::<FiniteF64>().unwrap(), FiniteF64(3.0));
+            let v = py
+                .eval(
+                    crate::ffi::c_str!("__import__('fractions').Fraction(1, 4)"),
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert_eq!(v.extract::<FiniteF64>().unwrap(), FiniteF64(0.25));
+
+            for (value, repr) in [
+                (f64::NAN, "nan"),
+                (f64::INFINITY, "inf"),
+                (f64::NEG_INFINITY, "-inf"),
+            ] {
+                let err = value
+                    .into_pyobject(py)
+                    .unwrap()
+                    .extract::<FiniteF64>()
+                    .unwrap_err();
+                assert!(err.is_instance_of::<PyValueError>(py));
+                assert_eq!(
+                    err.value(py).to_string(),
+                    format!("expected a finite float, got {}", repr)
+                );
+            }
+
+            assert!("1.0"
+                .into_pyobject(py)
+                .unwrap()
+                .extract::<FiniteF64>()
+                .is_err());
+        });
+    }
 }
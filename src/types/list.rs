@@ -1,14 +1,19 @@
 use crate::err::{self, PyResult};
+use crate::exceptions::{PyTypeError, PyValueError};
 use crate::ffi::{self, Py_ssize_t};
 use crate::ffi_ptr_ext::FfiPtrExt;
 use crate::internal_tricks::get_ssize_index;
+use crate::sync::GILOnceCell;
 use crate::types::any::PyAnyMethods;
 use crate::types::sequence::PySequenceMethods;
-use crate::types::{PySequence, PyTuple};
+use crate::types::{PySequence, PyString, PyTuple};
 use crate::{
-    Borrowed, Bound, BoundObject, IntoPyObject, IntoPyObjectExt, PyAny, PyErr, PyObject, Python,
+    Borrowed, Bound, BoundObject, FromPyObject, IntoPyObject, IntoPyObjectExt, PyAny, PyErr,
+    PyObject, Python,
 };
+use std::cmp::Ordering;
 use std::iter::FusedIterator;
+use std::marker::PhantomData;
 #[cfg(feature = "nightly")]
 use std::num::NonZero;
 
@@ -212,6 +217,34 @@ pub trait PyListMethods<'py>: crate::sealed::Sealed {
     /// This is equivalent to the Python statement `del self[low:high]`.
     fn del_slice(&self, low: usize, high: usize) -> PyResult<()>;
 
+    /// Replaces the items of `self` from `start` to `end` with the contents of `items`.
+    ///
+    /// This is equivalent to the Python statement `self[start:end] = items`. The list grows or
+    /// shrinks if the number of new items differs from the length of the replaced range, so an
+    /// empty `items` deletes the range. Out-of-range indices are clipped to `self.len()`.
+    ///
+    /// Raises `ValueError` if `start > end`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyList;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let list = PyList::new(py, [1, 2, 3, 4])?;
+    ///     list.replace_range(1, 3, [7, 8, 9])?;
+    ///     assert_eq!(list.extract::<Vec<i32>>()?, [1, 7, 8, 9, 4]);
+    /// # Ok(())
+    /// })
+    /// # }
+    /// ```
+    fn replace_range<I>(&self, start: usize, end: usize, items: I) -> PyResult<()>
+    where
+        I: IntoIterator,
+        I::Item: IntoPyObject<'py>;
+
     /// Appends an item to the list.
     fn append<I>(&self, item: I) -> PyResult<()>
     where
@@ -224,6 +257,35 @@ pub trait PyListMethods<'py>: crate::sealed::Sealed {
     where
         I: IntoPyObject<'py>;
 
+    /// Appends all items of `items` to the end of the list.
+    ///
+    /// Each item is converted by reference up front, and the list is grown once, which is faster than
+    /// calling [`append`](PyListMethods::append) for each item. Slices of `u8` are appended as integers,
+    /// use [`PyBytes`](crate::types::PyBytes) to create a `bytes` object instead.
+    ///
+    /// If converting any item fails, the error is returned and the list is left unchanged.
+    ///
+    /// The item type usually has to be named explicitly, as in `extend_from_slice::<i32>(..)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyList;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let list = PyList::new(py, [1, 2])?;
+    ///     list.extend_from_slice::<i32>(&[3, 4, 5])?;
+    ///     assert_eq!(list.extract::<Vec<i32>>()?, [1, 2, 3, 4, 5]);
+    /// # Ok(())
+    /// })
+    /// # }
+    /// ```
+    fn extend_from_slice<T>(&self, items: &[T]) -> PyResult<()>
+    where
+        for<'a> &'a T: IntoPyObject<'py>;
+
     /// Determines if self contains `value`.
     ///
     /// This is equivalent to the Python expression `value in self`.
@@ -241,6 +303,14 @@ pub trait PyListMethods<'py>: crate::sealed::Sealed {
     /// Returns an iterator over this list's items.
     fn iter(&self) -> BoundListIterator<'py>;
 
+    /// Returns an iterator over this list's items extracted as `T`, paired with their indices.
+    ///
+    /// Each item is extracted as it is reached, so an item which fails to extract yields an `Err`
+    /// for its index and iteration continues with the next item.
+    fn enumerate_extract<T>(&self) -> BoundListEnumerateExtract<'py, T>
+    where
+        T: FromPyObject<'py>;
+
     /// Iterates over the contents of this list while holding a critical section on the list.
     /// This is useful when the GIL is disabled and the list is shared between threads.
     /// It is not guaranteed that the list will not be modified during iteration when the
@@ -255,9 +325,65 @@ pub trait PyListMethods<'py>: crate::sealed::Sealed {
     /// Sorts the list in-place. Equivalent to the Python expression `l.sort()`.
     fn sort(&self) -> PyResult<()>;
 
+    /// Sorts the list in-place with a Rust comparator, like [`slice::sort_by`].
+    ///
+    /// The items are collected into a `Vec`, sorted with `compare` and written back with a
+    /// single `l[:] = sorted_items` assignment. This lets items be sorted by keys computed in
+    /// Rust without defining a Python key function. The sort is stable. If `compare` panics,
+    /// the list keeps its original order.
+    fn sort_by<F>(&self, compare: F) -> PyResult<()>
+    where
+        F: FnMut(&Bound<'py, PyAny>, &Bound<'py, PyAny>) -> Ordering;
+
     /// Reverses the list in-place. Equivalent to the Python expression `l.reverse()`.
     fn reverse(&self) -> PyResult<()>;
 
+    /// Rotates the list in-place so that the first `n` items move to the end.
+    ///
+    /// Equivalent to the Python expression `l[:] = l[n:] + l[:n]`. `n` is taken modulo the
+    /// length of the list, so rotating by a multiple of the length or rotating an empty list
+    /// leaves the list unchanged.
+    fn rotate_left(&self, n: usize) -> PyResult<()>;
+
+    /// Rotates the list in-place so that the last `n` items move to the front.
+    ///
+    /// Equivalent to the Python expression `l[:] = l[-n:] + l[:-n]`. `n` is taken modulo the
+    /// length of the list, so rotating by a multiple of the length or rotating an empty list
+    /// leaves the list unchanged.
+    fn rotate_right(&self, n: usize) -> PyResult<()>;
+
+    /// Returns the sum of the items in the list. Equivalent to the Python expression `sum(l)`.
+    ///
+    /// The result follows Python's numeric type promotion, e.g. summing `int`s gives an `int`
+    /// while mixing in a `float` gives a `float`. An empty list sums to `0`.
+    fn sum(&self) -> PyResult<Bound<'py, PyAny>>;
+
+    /// Returns the product of the items in the list. Equivalent to the Python expression
+    /// `math.prod(l)`.
+    ///
+    /// The result follows Python's numeric type promotion, as for [`sum`](PyListMethods::sum).
+    /// The product of an empty list is `1`.
+    fn product(&self) -> PyResult<Bound<'py, PyAny>>;
+
+    /// Splits the list into two new lists according to a predicate, like
+    /// [`Iterator::partition`].
+    ///
+    /// The first list contains the items for which `f` returned `true`, the second those for
+    /// which it returned `false`. Items keep their relative order. If `f` returns an error,
+    /// partitioning stops and the error is returned.
+    fn partition<F>(&self, f: F) -> PyResult<(Bound<'py, PyList>, Bound<'py, PyList>)>
+    where
+        F: FnMut(&Bound<'py, PyAny>) -> PyResult<bool>;
+
+    /// Flattens a list of iterables by one level, returning a new list containing the items of
+    /// each element in turn.
+    ///
+    /// This is equivalent to the Python expression `[x for item in l for x in item]`, except for
+    /// `str` elements: these are only expanded into their characters if `split_strings` is
+    /// `true`, otherwise they raise a `TypeError` as it is rarely intended to flatten text.
+    /// Elements which are not iterable also raise a `TypeError`.
+    fn flatten(&self, split_strings: bool) -> PyResult<Bound<'py, PyList>>;
+
     /// Return a new tuple containing the contents of the list; equivalent to the Python expression `tuple(list)`.
     ///
     /// This method is equivalent to `self.as_sequence().to_tuple()` and faster than `PyTuple::new(py, this_list)`.
@@ -383,6 +509,26 @@ impl<'py> PyListMethods<'py> for Bound<'py, PyList> {
         self.as_sequence().del_slice(low, high)
     }
 
+    fn replace_range<I>(&self, start: usize, end: usize, items: I) -> PyResult<()>
+    where
+        I: IntoIterator,
+        I::Item: IntoPyObject<'py>,
+    {
+        if start > end {
+            return Err(PyValueError::new_err(format!(
+                "range start {} is greater than range end {}",
+                start, end
+            )));
+        }
+        let py = self.py();
+        let items = items
+            .into_iter()
+            .map(|item| item.into_bound_py_any(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        let items = try_new_from_iter(py, items.into_iter().map(Ok))?;
+        self.set_slice(start, end, &items)
+    }
+
     /// Appends an item to the list.
     fn append<I>(&self, item: I) -> PyResult<()>
     where
@@ -426,6 +572,18 @@ impl<'py> PyListMethods<'py> for Bound<'py, PyList> {
         )
     }
 
+    fn extend_from_slice<T>(&self, items: &[T]) -> PyResult<()>
+    where
+        for<'a> &'a T: IntoPyObject<'py>,
+    {
+        if items.is_empty() {
+            return Ok(());
+        }
+        let items = PyList::new(self.py(), items)?;
+        let len = self.len();
+        self.set_slice(len, len, &items)
+    }
+
     /// Determines if self contains `value`.
     ///
     /// This is equivalent to the Python expression `value in self`.
@@ -453,6 +611,16 @@ impl<'py> PyListMethods<'py> for Bound<'py, PyList> {
         BoundListIterator::new(self.clone())
     }
 
+    fn enumerate_extract<T>(&self) -> BoundListEnumerateExtract<'py, T>
+    where
+        T: FromPyObject<'py>,
+    {
+        BoundListEnumerateExtract {
+            inner: self.iter().enumerate(),
+            _marker: PhantomData,
+        }
+    }
+
     /// Iterates over a list while holding a critical section, calling a closure on each item
     fn locked_for_each<F>(&self, closure: F) -> PyResult<()>
     where
@@ -466,11 +634,85 @@ impl<'py> PyListMethods<'py> for Bound<'py, PyList> {
         err::error_on_minusone(self.py(), unsafe { ffi::PyList_Sort(self.as_ptr()) })
     }
 
+    fn sort_by<F>(&self, compare: F) -> PyResult<()>
+    where
+        F: FnMut(&Bound<'py, PyAny>, &Bound<'py, PyAny>) -> Ordering,
+    {
+        let mut items: Vec<_> = self.iter().collect();
+        // the list is only modified once sorting has completed, so a panic leaves it untouched
+        items.sort_by(compare);
+        let sorted = try_new_from_iter(self.py(), items.into_iter().map(Ok))?;
+        self.set_slice(0, usize::MAX, &sorted)
+    }
+
     /// Reverses the list in-place. Equivalent to the Python expression `l.reverse()`.
     fn reverse(&self) -> PyResult<()> {
         err::error_on_minusone(self.py(), unsafe { ffi::PyList_Reverse(self.as_ptr()) })
     }
 
+    fn rotate_left(&self, n: usize) -> PyResult<()> {
+        let len = self.len();
+        if len == 0 || n % len == 0 {
+            return Ok(());
+        }
+        let mid = n % len;
+        let rotated = self.get_slice(mid, len);
+        rotated.set_slice(len - mid, len - mid, &self.get_slice(0, mid))?;
+        self.set_slice(0, len, &rotated)
+    }
+
+    fn rotate_right(&self, n: usize) -> PyResult<()> {
+        let len = self.len();
+        if len == 0 {
+            return Ok(());
+        }
+        self.rotate_left(len - n % len)
+    }
+
+    fn sum(&self) -> PyResult<Bound<'py, PyAny>> {
+        static SUM: GILOnceCell<PyObject> = GILOnceCell::new();
+        SUM.import(self.py(), "builtins", "sum")?.call1((self,))
+    }
+
+    fn product(&self) -> PyResult<Bound<'py, PyAny>> {
+        // `math.prod` is only available from Python 3.8, so multiply the items directly
+        self.iter()
+            .try_fold(1.into_bound_py_any(self.py())?, |product, item| {
+                product.mul(item)
+            })
+    }
+
+    fn partition<F>(&self, mut f: F) -> PyResult<(Bound<'py, PyList>, Bound<'py, PyList>)>
+    where
+        F: FnMut(&Bound<'py, PyAny>) -> PyResult<bool>,
+    {
+        let matching = PyList::empty(self.py());
+        let rest = PyList::empty(self.py());
+        for item in self {
+            if f(&item)? {
+                matching.append(item)?;
+            } else {
+                rest.append(item)?;
+            }
+        }
+        Ok((matching, rest))
+    }
+
+    fn flatten(&self, split_strings: bool) -> PyResult<Bound<'py, PyList>> {
+        let flattened = PyList::empty(self.py());
+        for item in self {
+            if !split_strings && item.is_instance_of::<PyString>() {
+                return Err(PyTypeError::new_err(
+                    "cannot flatten a 'str' element unless strings are split",
+                ));
+            }
+            for inner in item.try_iter()? {
+                flattened.append(inner?)?;
+            }
+        }
+        Ok(flattened)
+    }
+
     /// Return a new tuple containing the contents of the list; equivalent to the Python expression `tuple(list)`.
     ///
     /// This method is equivalent to `self.as_sequence().to_tuple()` and faster than `PyTuple::new(py, this_list)`.
@@ -952,6 +1194,42 @@ impl ExactSizeIterator for BoundListIterator<'_> {
 
 impl FusedIterator for BoundListIterator<'_> {}
 
+/// Used by `PyList::enumerate_extract()`.
+pub struct BoundListEnumerateExtract<'py, T> {
+    inner: std::iter::Enumerate<BoundListIterator<'py>>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'py, T> Iterator for BoundListEnumerateExtract<'py, T>
+where
+    T: FromPyObject<'py>,
+{
+    type Item = PyResult<(usize, T)>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(index, item)| item.extract().map(|value| (index, value)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'py, T> ExactSizeIterator for BoundListEnumerateExtract<'py, T>
+where
+    T: FromPyObject<'py>,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'py, T> FusedIterator for BoundListEnumerateExtract<'py, T> where T: FromPyObject<'py> {}
+
 impl<'py> IntoIterator for Bound<'py, PyList> {
     type Item = Bound<'py, PyAny>;
     type IntoIter = BoundListIterator<'py>;
@@ -972,11 +1250,12 @@ impl<'py> IntoIterator for &Bound<'py, PyList> {
 
 #[cfg(test)]
 mod tests {
+    use crate::exceptions::PyTypeError;
     use crate::types::any::PyAnyMethods;
     use crate::types::list::PyListMethods;
     use crate::types::sequence::PySequenceMethods;
-    use crate::types::{PyList, PyTuple};
-    use crate::{ffi, IntoPyObject, PyResult, Python};
+    use crate::types::{PyFloat, PyInt, PyList, PyTuple};
+    use crate::{ffi, IntoPyObject, IntoPyObjectExt, PyResult, Python};
     #[cfg(feature = "nightly")]
     use std::num::NonZero;
 
@@ -1068,6 +1347,32 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_extend_from_slice() {
+        Python::with_gil(|py| {
+            let list = PyList::new(py, [1, 2]).unwrap();
+            list.extend_from_slice::<i32>(&[]).unwrap();
+            assert_eq!(list.len(), 2);
+
+            let more = vec![3, 4, 5];
+            list.extend_from_slice::<i32>(&more).unwrap();
+            assert_eq!(list.extract::<Vec<i32>>().unwrap(), [1, 2, 3, 4, 5]);
+
+            let list = PyList::empty(py);
+            list.extend_from_slice::<u8>(b"ab").unwrap();
+            assert!(list
+                .get_item(0)
+                .unwrap()
+                .is_instance_of::<crate::types::PyInt>());
+            assert_eq!(list.extract::<Vec<u8>>().unwrap(), b"ab");
+
+            let list = PyList::empty(py);
+            list.extend_from_slice::<String>(&[String::from("a"), String::from("b")])
+                .unwrap();
+            assert_eq!(list.extract::<Vec<String>>().unwrap(), ["a", "b"]);
+        });
+    }
+
     #[test]
     fn test_insert_refcnt() {
         Python::with_gil(|py| {
@@ -1477,6 +1782,198 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_list_replace_range() {
+        Python::with_gil(|py| {
+            let list = PyList::new(py, [1, 2, 3, 4, 5]).unwrap();
+            // more items than replaced
+            list.replace_range(1, 3, [7, 8, 9]).unwrap();
+            assert_eq!([1, 7, 8, 9, 4, 5], list.extract::<[i32; 6]>().unwrap());
+            // fewer items than replaced
+            list.replace_range(1, 4, vec![6]).unwrap();
+            assert_eq!([1, 6, 4, 5], list.extract::<[i32; 4]>().unwrap());
+            // no items deletes the range
+            list.replace_range(1, 3, Vec::<i32>::new()).unwrap();
+            assert_eq!([1, 5], list.extract::<[i32; 2]>().unwrap());
+            // out-of-range indices are clipped
+            list.replace_range(1, 100, [2, 3]).unwrap();
+            assert_eq!([1, 2, 3], list.extract::<[i32; 3]>().unwrap());
+            list.replace_range(50, 100, [4]).unwrap();
+            assert_eq!([1, 2, 3, 4], list.extract::<[i32; 4]>().unwrap());
+
+            let err = list.replace_range(3, 1, [0]).unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyValueError>(py));
+            assert_eq!([1, 2, 3, 4], list.extract::<[i32; 4]>().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_list_sum_and_product() {
+        Python::with_gil(|py| {
+            let list = PyList::new(py, [1, 2, 3, 4]).unwrap();
+            let sum = list.sum().unwrap();
+            assert!(sum.is_exact_instance_of::<PyInt>());
+            assert_eq!(sum.extract::<i32>().unwrap(), 10);
+            let product = list.product().unwrap();
+            assert!(product.is_exact_instance_of::<PyInt>());
+            assert_eq!(product.extract::<i32>().unwrap(), 24);
+
+            let list = PyList::new(
+                py,
+                [
+                    1.into_bound_py_any(py).unwrap(),
+                    2.5.into_bound_py_any(py).unwrap(),
+                ],
+            )
+            .unwrap();
+            let sum = list.sum().unwrap();
+            assert!(sum.is_exact_instance_of::<PyFloat>());
+            assert_eq!(sum.extract::<f64>().unwrap(), 3.5);
+            assert_eq!(list.product().unwrap().extract::<f64>().unwrap(), 2.5);
+
+            let empty = PyList::empty(py);
+            assert_eq!(empty.sum().unwrap().extract::<i32>().unwrap(), 0);
+            assert_eq!(empty.product().unwrap().extract::<i32>().unwrap(), 1);
+
+            let list = PyList::new(
+                py,
+                [1.into_bound_py_any(py).unwrap(), py.None().into_bound(py)],
+            )
+            .unwrap();
+            assert!(list.sum().unwrap_err().is_instance_of::<PyTypeError>(py));
+            assert!(list
+                .product()
+                .unwrap_err()
+                .is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn test_list_partition() {
+        Python::with_gil(|py| {
+            let list = PyList::new(py, [1, 2, 3, 4, 5, 6, 7]).unwrap();
+            let (even, odd) = list
+                .partition(|item| Ok(item.extract::<i32>()? % 2 == 0))
+                .unwrap();
+            assert_eq!(even.extract::<Vec<i32>>().unwrap(), [2, 4, 6]);
+            assert_eq!(odd.extract::<Vec<i32>>().unwrap(), [1, 3, 5, 7]);
+            // the original list is unchanged
+            assert_eq!(list.len(), 7);
+
+            let (even, odd) = PyList::empty(py).partition(|_| Ok(true)).unwrap();
+            assert!(even.is_empty());
+            assert!(odd.is_empty());
+
+            let list = PyList::new(
+                py,
+                [1.into_bound_py_any(py).unwrap(), py.None().into_bound(py)],
+            )
+            .unwrap();
+            let err = list
+                .partition(|item| Ok(item.extract::<i32>()? % 2 == 0))
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn test_list_flatten() {
+        Python::with_gil(|py| {
+            let list = PyList::new(py, [vec![1, 2], vec![], vec![3]]).unwrap();
+            let flattened = list.flatten(false).unwrap();
+            assert_eq!(flattened.extract::<Vec<i32>>().unwrap(), [1, 2, 3]);
+
+            let list = PyList::new(py, ["ab", "c"]).unwrap();
+            let err = list.flatten(false).unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+            let flattened = list.flatten(true).unwrap();
+            assert_eq!(flattened.extract::<Vec<String>>().unwrap(), ["a", "b", "c"]);
+
+            let list = PyList::new(
+                py,
+                [
+                    PyList::new(py, [1]).unwrap().into_any(),
+                    2.into_bound_py_any(py).unwrap(),
+                ],
+            )
+            .unwrap();
+            let err = list.flatten(true).unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+
+    #[test]
+    fn test_list_sort_by() {
+        Python::with_gil(|py| {
+            let list = PyList::new(py, ["10", "9", "100", "1"]).unwrap();
+            list.sort_by(|a, b| {
+                let a: i32 = a.extract::<String>().unwrap().parse().unwrap();
+                let b: i32 = b.extract::<String>().unwrap().parse().unwrap();
+                a.cmp(&b)
+            })
+            .unwrap();
+            assert_eq!(
+                list.extract::<Vec<String>>().unwrap(),
+                ["1", "9", "10", "100"]
+            );
+
+            // the sort is stable
+            let list = PyList::new(py, [(1, "a"), (0, "b"), (1, "c"), (0, "d")]).unwrap();
+            list.sort_by(|a, b| {
+                let a: i32 = a.get_item(0).unwrap().extract().unwrap();
+                let b: i32 = b.get_item(0).unwrap().extract().unwrap();
+                a.cmp(&b)
+            })
+            .unwrap();
+            assert_eq!(
+                list.extract::<Vec<(i32, String)>>().unwrap(),
+                [
+                    (0, "b".to_owned()),
+                    (0, "d".to_owned()),
+                    (1, "a".to_owned()),
+                    (1, "c".to_owned())
+                ]
+            );
+
+            // a panicking comparator leaves the list unchanged
+            let list = PyList::new(py, [3, 1, 2]).unwrap();
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.sort_by(|_, _| panic!("comparator panicked"))
+            }));
+            assert!(result.is_err());
+            assert_eq!(list.extract::<Vec<i32>>().unwrap(), [3, 1, 2]);
+        });
+    }
+
+    #[test]
+    fn test_list_rotate() {
+        Python::with_gil(|py| {
+            let list = PyList::new(py, [1, 2, 3, 4, 5]).unwrap();
+            list.rotate_left(2).unwrap();
+            assert_eq!(list.extract::<Vec<i32>>().unwrap(), [3, 4, 5, 1, 2]);
+            list.rotate_right(2).unwrap();
+            assert_eq!(list.extract::<Vec<i32>>().unwrap(), [1, 2, 3, 4, 5]);
+            list.rotate_right(1).unwrap();
+            assert_eq!(list.extract::<Vec<i32>>().unwrap(), [5, 1, 2, 3, 4]);
+
+            // shifts longer than the list wrap around
+            list.rotate_left(7).unwrap();
+            assert_eq!(list.extract::<Vec<i32>>().unwrap(), [2, 3, 4, 5, 1]);
+            list.rotate_right(11).unwrap();
+            assert_eq!(list.extract::<Vec<i32>>().unwrap(), [1, 2, 3, 4, 5]);
+
+            // multiples of the length are no-ops
+            list.rotate_left(10).unwrap();
+            list.rotate_right(5).unwrap();
+            assert_eq!(list.extract::<Vec<i32>>().unwrap(), [1, 2, 3, 4, 5]);
+
+            let empty = PyList::empty(py);
+            empty.rotate_left(3).unwrap();
+            empty.rotate_right(3).unwrap();
+            assert!(empty.is_empty());
+        });
+    }
+
     #[test]
     fn test_list_del_slice() {
         Python::with_gil(|py| {
@@ -1811,4 +2308,23 @@ mod tests {
             assert_eq!(list.iter().count(), 3);
         })
     }
+
+    #[test]
+    fn test_list_enumerate_extract() {
+        Python::with_gil(|py| {
+            let list = PyList::new(py, ["a".into_pyobject(py).unwrap().into_any()]).unwrap();
+            list.append(1).unwrap();
+            list.append("c").unwrap();
+
+            let mut iter = list.enumerate_extract::<String>();
+            assert_eq!(iter.len(), 3);
+            assert_eq!(iter.next().unwrap().unwrap(), (0, "a".to_owned()));
+            assert!(iter.next().unwrap().is_err());
+            assert_eq!(iter.next().unwrap().unwrap(), (2, "c".to_owned()));
+            assert!(iter.next().is_none());
+
+            let empty = PyList::empty(py);
+            assert_eq!(empty.enumerate_extract::<String>().count(), 0);
+        });
+    }
 }
@@ -335,4 +335,59 @@ mod tests {
             assert_eq!(32 + 42 + 123, values_sum);
         });
     }
+
+    #[test]
+    fn test_views_of_non_dict_mappings() {
+        Python::with_gil(|py| {
+            let locals = crate::types::PyDict::new(py);
+            py.run(
+                ffi::c_str!(
+                    r#"
+import collections.abc, types
+
+class DictSubclass(dict):
+    pass
+
+class Custom(collections.abc.Mapping):
+    def __init__(self, data):
+        self._data = data
+    def __getitem__(self, key):
+        return self._data[key]
+    def __iter__(self):
+        return iter(self._data)
+    def __len__(self):
+        return len(self._data)
+
+data = {"a": 1, "b": 2}
+mappings = [DictSubclass(data), Custom(data), types.MappingProxyType(data)]
+"#
+                ),
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+
+            let mappings = locals.get_item("mappings").unwrap();
+            for mapping in mappings.try_iter().unwrap() {
+                let mapping = mapping.unwrap();
+                let mapping = mapping.downcast::<PyMapping>().unwrap();
+                assert_eq!(
+                    mapping.keys().unwrap().extract::<Vec<String>>().unwrap(),
+                    ["a", "b"]
+                );
+                assert_eq!(
+                    mapping.values().unwrap().extract::<Vec<i32>>().unwrap(),
+                    [1, 2]
+                );
+                assert_eq!(
+                    mapping
+                        .items()
+                        .unwrap()
+                        .extract::<Vec<(String, i32)>>()
+                        .unwrap(),
+                    [("a".to_owned(), 1), ("b".to_owned(), 2)]
+                );
+            }
+        });
+    }
 }
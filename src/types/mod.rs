@@ -2,11 +2,11 @@
 
 pub use self::any::{PyAny, PyAnyMethods};
 pub use self::boolobject::{PyBool, PyBoolMethods};
-pub use self::bytearray::{PyByteArray, PyByteArrayMethods};
+pub use self::bytearray::{PyByteArray, PyByteArrayMethods, PyByteArrayMutGuard};
 pub use self::bytes::{PyBytes, PyBytesMethods};
 pub use self::capsule::{PyCapsule, PyCapsuleMethods};
 #[cfg(all(not(Py_LIMITED_API), not(PyPy), not(GraalPy)))]
-pub use self::code::PyCode;
+pub use self::code::{PyCode, PyCodeMethods};
 pub use self::complex::{PyComplex, PyComplexMethods};
 #[cfg(not(Py_LIMITED_API))]
 #[allow(deprecated)]
@@ -18,7 +18,7 @@ pub use self::dict::{IntoPyDict, PyDict, PyDictMethods};
 #[cfg(not(any(PyPy, GraalPy)))]
 pub use self::dict::{PyDictItems, PyDictKeys, PyDictValues};
 pub use self::ellipsis::PyEllipsis;
-pub use self::float::{PyFloat, PyFloatMethods};
+pub use self::float::{FiniteF64, PyFloat, PyFloatMethods};
 #[cfg(all(not(Py_LIMITED_API), not(PyPy), not(GraalPy)))]
 pub use self::frame::PyFrame;
 pub use self::frozenset::{PyFrozenSet, PyFrozenSetBuilder, PyFrozenSetMethods};
@@ -48,7 +48,7 @@ pub use self::string::PyStringData;
 pub use self::string::{PyString, PyStringMethods, PyUnicode};
 pub use self::traceback::{PyTraceback, PyTracebackMethods};
 pub use self::tuple::{PyTuple, PyTupleMethods};
-pub use self::typeobject::{PyType, PyTypeMethods};
+pub use self::typeobject::{PyType, PyTypeBuilder, PyTypeMethods};
 pub use self::weakref::{PyWeakref, PyWeakrefMethods, PyWeakrefProxy, PyWeakrefReference};
 
 /// Iteration over Python collections.
@@ -85,9 +85,10 @@ pub use self::weakref::{PyWeakref, PyWeakrefMethods, PyWeakrefProxy, PyWeakrefRe
 pub mod iter {
     pub use super::dict::BoundDictIterator;
     pub use super::frozenset::BoundFrozenSetIterator;
-    pub use super::list::BoundListIterator;
+    pub use super::list::{BoundListEnumerateExtract, BoundListIterator};
     pub use super::set::BoundSetIterator;
     pub use super::tuple::{BorrowedTupleIterator, BoundTupleIterator};
+    pub use super::typeobject::BoundMroIterator;
 }
 
 /// Python objects that have a base type.
@@ -221,7 +222,7 @@ pub(crate) mod bytearray;
 pub(crate) mod bytes;
 pub(crate) mod capsule;
 #[cfg(all(not(Py_LIMITED_API), not(PyPy), not(GraalPy)))]
-mod code;
+pub(crate) mod code;
 pub(crate) mod complex;
 #[cfg(not(Py_LIMITED_API))]
 pub(crate) mod datetime;
@@ -3,8 +3,10 @@ use crate::ffi_ptr_ext::FfiPtrExt;
 use crate::impl_::callback::IntoPyCallbackOutput;
 use crate::py_result_ext::PyResultExt;
 use crate::pyclass::PyClass;
+use crate::sync::{GILOnceCell, OnceExt};
 use crate::types::{
-    any::PyAnyMethods, list::PyListMethods, PyAny, PyCFunction, PyDict, PyList, PyString,
+    any::PyAnyMethods, dict::PyDictMethods, list::PyListMethods, PyAny, PyCFunction, PyDict,
+    PyList, PyString, PyWeakrefMethods, PyWeakrefReference,
 };
 use crate::{
     exceptions, ffi, Borrowed, Bound, BoundObject, IntoPyObject, IntoPyObjectExt, Py, PyObject,
@@ -14,6 +16,7 @@ use std::ffi::{CStr, CString};
 #[cfg(all(not(Py_LIMITED_API), Py_GIL_DISABLED))]
 use std::os::raw::c_int;
 use std::str;
+use std::sync::{Mutex, Once};
 
 /// Represents a Python [`module`][1] object.
 ///
@@ -304,6 +307,64 @@ pub trait PyModuleMethods<'py>: crate::sealed::Sealed {
     where
         T: PyClass;
 
+    /// Adds a class to the module under `name` instead of its own name.
+    ///
+    /// This can be used to expose a class under a Python name which differs from the
+    /// `#[pyclass]`'s name, or to expose the same class under several aliases. The class's own
+    /// `__name__` and `__qualname__` are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    ///
+    /// #[pyclass]
+    /// struct Foo { /* fields omitted */ }
+    ///
+    /// #[pymodule]
+    /// fn my_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    ///     module.add_class::<Foo>()?;
+    ///     module.add_class_with_name::<Foo>("LegacyFoo")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    fn add_class_with_name<T>(&self, name: &str) -> PyResult<()>
+    where
+        T: PyClass;
+
+    /// Adds a single, pre-built object to the module, such as a configured instance of a
+    /// `#[pyclass]`, for use as a module-level singleton.
+    ///
+    /// This behaves like [`PyModuleMethods::add`], except that adding an object under a name
+    /// which is already in use replaces the previous object without listing the name in
+    /// `__all__` twice.
+    ///
+    /// The `__module__` reported for an instance is that of its class, so a `#[pyclass]` whose
+    /// instances are exposed this way should usually be declared with
+    /// `#[pyclass(module = "...")]` to name the module it lives in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    ///
+    /// #[pyclass(module = "my_module")]
+    /// struct Registry {
+    ///     #[pyo3(get)]
+    ///     verbose: bool,
+    /// }
+    ///
+    /// #[pymodule]
+    /// fn my_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    ///     module.add_instance("registry", Registry { verbose: true })?;
+    ///     Ok(())
+    /// }
+    /// ```
+    fn add_instance<N, V>(&self, name: N, value: V) -> PyResult<()>
+    where
+        N: IntoPyObject<'py, Target = PyString>,
+        V: IntoPyObject<'py>;
+
     /// Adds a function or a (sub)module to a module, using the functions name as name.
     ///
     /// Prefer to use [`PyModuleMethods::add_function`] and/or [`PyModuleMethods::add_submodule`]
@@ -390,6 +451,41 @@ pub trait PyModuleMethods<'py>: crate::sealed::Sealed {
     /// [2]: crate::wrap_pyfunction
     fn add_function(&self, fun: Bound<'_, PyCFunction>) -> PyResult<()>;
 
+    /// Adds an attribute to the module whose value is only created when it is first accessed.
+    ///
+    /// This is useful for attributes which are expensive to build and rarely used, as it keeps
+    /// importing the module cheap. The attribute is resolved through a module-level
+    /// `__getattr__` (see [PEP 562](https://peps.python.org/pep-0562/)) which calls `f` on first
+    /// access and caches the result as a regular module attribute. `f` runs at most once, even
+    /// if several threads access the attribute concurrently; if it fails, the same error is
+    /// raised on every access.
+    ///
+    /// Lazy attributes are listed by `dir(module)` and added to `__all__`. If the module already
+    /// defines `__getattr__`, it is still called for names which are not lazy attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyDict;
+    ///
+    /// #[pymodule]
+    /// fn my_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    ///     module.add_lazy("table", |py| {
+    ///         let table = PyDict::new(py);
+    ///         for i in 0..1000 {
+    ///             table.set_item(i, i * i)?;
+    ///         }
+    ///         Ok(table.into_any().unbind())
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// ```
+    fn add_lazy<N, F>(&self, name: N, f: F) -> PyResult<()>
+    where
+        N: IntoPyObject<'py, Target = PyString>,
+        F: FnOnce(Python<'_>) -> PyResult<PyObject> + Send + 'static;
+
     /// Declare whether or not this module supports running with the GIL disabled
     ///
     /// If the module does not rely on the GIL for thread safety, you can pass
@@ -465,6 +561,7 @@ impl<'py> PyModuleMethods<'py> for Bound<'py, PyModule> {
         #[cfg(PyPy)]
         {
             self.dict()
+                .as_any()
                 .get_item("__name__")
                 .map_err(|_| exceptions::PyAttributeError::new_err("__name__"))?
                 .downcast_into()
@@ -483,6 +580,7 @@ impl<'py> PyModuleMethods<'py> for Bound<'py, PyModule> {
         #[cfg(PyPy)]
         {
             self.dict()
+                .as_any()
                 .get_item("__file__")
                 .map_err(|_| exceptions::PyAttributeError::new_err("__file__"))?
                 .downcast_into()
@@ -523,6 +621,39 @@ impl<'py> PyModuleMethods<'py> for Bound<'py, PyModule> {
         self.add(T::NAME, T::lazy_type_object().get_or_try_init(py)?)
     }
 
+    fn add_class_with_name<T>(&self, name: &str) -> PyResult<()>
+    where
+        T: PyClass,
+    {
+        let py = self.py();
+        self.add(name, T::lazy_type_object().get_or_try_init(py)?)
+    }
+
+    fn add_instance<N, V>(&self, name: N, value: V) -> PyResult<()>
+    where
+        N: IntoPyObject<'py, Target = PyString>,
+        V: IntoPyObject<'py>,
+    {
+        fn inner(
+            module: &Bound<'_, PyModule>,
+            name: Borrowed<'_, '_, PyString>,
+            value: Borrowed<'_, '_, PyAny>,
+        ) -> PyResult<()> {
+            let all = module.index()?;
+            if !all.contains(name)? {
+                all.append(name)?;
+            }
+            module.setattr(name, value)
+        }
+
+        let py = self.py();
+        inner(
+            self,
+            name.into_pyobject_or_pyerr(py)?.as_borrowed(),
+            value.into_pyobject_or_pyerr(py)?.into_any().as_borrowed(),
+        )
+    }
+
     fn add_wrapped<T>(&self, wrapper: &impl Fn(Python<'py>) -> T) -> PyResult<()>
     where
         T: IntoPyCallbackOutput<'py, PyObject>,
@@ -546,6 +677,49 @@ impl<'py> PyModuleMethods<'py> for Bound<'py, PyModule> {
         self.add(name.downcast_into::<PyString>()?, fun)
     }
 
+    fn add_lazy<N, F>(&self, name: N, f: F) -> PyResult<()>
+    where
+        N: IntoPyObject<'py, Target = PyString>,
+        F: FnOnce(Python<'_>) -> PyResult<PyObject> + Send + 'static,
+    {
+        fn inner(
+            module: &Bound<'_, PyModule>,
+            name: Borrowed<'_, '_, PyString>,
+            factory: Bound<'_, PyCFunction>,
+        ) -> PyResult<()> {
+            module
+                .index()?
+                .append(name)
+                .expect("could not append __name__ to __all__");
+            lazy_attributes(module)?.set_item(name, factory)
+        }
+
+        let py = self.py();
+        let once = Once::new();
+        let f = Mutex::new(Some(f));
+        let value = GILOnceCell::new();
+        let factory = PyCFunction::new_closure(py, None, None, move |args, _kwargs| {
+            let py = args.py();
+            once.call_once_py_attached(py, || {
+                let f = f
+                    .lock()
+                    .unwrap()
+                    .take()
+                    .expect("lazy attribute created twice");
+                let _ = value.set(py, f(py));
+            });
+            match value.get(py).expect("lazy attribute factory panicked") {
+                Ok(value) => Ok(value.clone_ref(py)),
+                Err(err) => Err(err.clone_ref(py)),
+            }
+        })?;
+        inner(
+            self,
+            name.into_pyobject_or_pyerr(py)?.as_borrowed(),
+            factory,
+        )
+    }
+
     #[cfg_attr(any(Py_LIMITED_API, not(Py_GIL_DISABLED)), allow(unused_variables))]
     fn gil_used(&self, gil_used: bool) -> PyResult<()> {
         #[cfg(all(not(Py_LIMITED_API), Py_GIL_DISABLED))]
@@ -564,6 +738,86 @@ impl<'py> PyModuleMethods<'py> for Bound<'py, PyModule> {
     }
 }
 
+/// Returns the factories of the lazy attributes of `module`, keyed by name.
+///
+/// On first use this installs the module `__getattr__` and `__dir__` which resolve and list them.
+fn lazy_attributes<'py>(module: &Bound<'py, PyModule>) -> PyResult<Bound<'py, PyDict>> {
+    let py = module.py();
+    let dict = module.dict();
+    if let Some(registry) = dict.get_item(__pyo3_lazy__(py))? {
+        return registry.downcast_into().map_err(PyErr::from);
+    }
+
+    let registry = PyDict::new(py);
+    let module_name = module.name()?.unbind();
+    // `__getattr__` and `__dir__` live in the module's own namespace, so hold the module weakly
+    // to avoid a reference cycle which could never be collected.
+    let weak_module = PyWeakrefReference::new(module)?.unbind();
+    let fallback = dict
+        .get_item(intern!(py, "__getattr__"))?
+        .map(Bound::unbind);
+
+    let getattr = {
+        let registry = registry.clone().unbind();
+        let weak_module = weak_module.clone_ref(py);
+        PyCFunction::new_closure(
+            py,
+            Some(ffi::c_str!("__getattr__")),
+            None,
+            move |args, _kwargs| -> PyResult<PyObject> {
+                let py = args.py();
+                let name = args.get_item(0)?.downcast_into::<PyString>()?;
+                if let Some(factory) = registry.bind(py).get_item(&name)? {
+                    let value = factory.call0()?;
+                    // later lookups find the cached value without reaching `__getattr__`
+                    if let Some(module) = weak_module.bind(py).upgrade() {
+                        module.setattr(&name, &value)?;
+                    }
+                    return Ok(value.unbind());
+                }
+                match &fallback {
+                    Some(fallback) => fallback.call1(py, (name,)),
+                    None => Err(exceptions::PyAttributeError::new_err(format!(
+                        "module '{}' has no attribute '{}'",
+                        module_name, name
+                    ))),
+                }
+            },
+        )?
+    };
+
+    let dir = {
+        let registry = registry.clone().unbind();
+        PyCFunction::new_closure(
+            py,
+            Some(ffi::c_str!("__dir__")),
+            None,
+            move |args, _kwargs| -> PyResult<PyObject> {
+                let py = args.py();
+                let names = match weak_module.bind(py).upgrade() {
+                    Some(module) => module.downcast_into::<PyModule>()?.dict().keys(),
+                    None => PyList::empty(py),
+                };
+                for name in registry.bind(py).keys() {
+                    if !names.contains(&name)? {
+                        names.append(name)?;
+                    }
+                }
+                Ok(names.into_any().unbind())
+            },
+        )?
+    };
+
+    dict.set_item(__pyo3_lazy__(py), &registry)?;
+    dict.set_item(intern!(py, "__getattr__"), getattr)?;
+    dict.set_item(intern!(py, "__dir__"), dir)?;
+    Ok(registry)
+}
+
+fn __pyo3_lazy__(py: Python<'_>) -> &Bound<'_, PyString> {
+    intern!(py, "__pyo3_lazy__")
+}
+
 fn __all__(py: Python<'_>) -> &Bound<'_, PyString> {
     intern!(py, "__all__")
 }
@@ -600,4 +854,91 @@ mod tests {
                 .ends_with("site.py"));
         })
     }
+
+    #[test]
+    fn module_add_lazy() {
+        use crate::exceptions::{PyAttributeError, PyValueError};
+        use crate::types::PyAnyMethods;
+        use crate::IntoPyObjectExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "lazy").unwrap();
+            let calls = Arc::new(AtomicUsize::new(0));
+            let counter = calls.clone();
+            module
+                .add_lazy("answer", move |py| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    42.into_py_any(py)
+                })
+                .unwrap();
+            module
+                .add_lazy("broken", |_py| Err(PyValueError::new_err("broken")))
+                .unwrap();
+
+            assert_eq!(calls.load(Ordering::SeqCst), 0);
+            let dir = py
+                .import("builtins")
+                .unwrap()
+                .call_method1("dir", (&module,))
+                .unwrap();
+            assert!(dir.contains("answer").unwrap());
+            assert!(dir.contains("broken").unwrap());
+            assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+            for _ in 0..3 {
+                let answer: i32 = module.getattr("answer").unwrap().extract().unwrap();
+                assert_eq!(answer, 42);
+            }
+            assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+            for _ in 0..2 {
+                let err = module.getattr("broken").unwrap_err();
+                assert!(err.is_instance_of::<PyValueError>(py));
+            }
+
+            let err = module.getattr("missing").unwrap_err();
+            assert!(err.is_instance_of::<PyAttributeError>(py));
+            assert_eq!(
+                err.value(py).to_string(),
+                "module 'lazy' has no attribute 'missing'"
+            );
+        })
+    }
+
+    #[test]
+    fn module_add_lazy_concurrent_access() {
+        use crate::IntoPyObjectExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let module = Python::with_gil(|py| {
+            let module = PyModule::new(py, "lazy").unwrap();
+            module
+                .add_lazy("value", move |py| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    // give other threads the chance to race for the value
+                    py.allow_threads(|| std::thread::sleep(std::time::Duration::from_millis(50)));
+                    "value".into_py_any(py)
+                })
+                .unwrap();
+            module.unbind()
+        });
+
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    Python::with_gil(|py| {
+                        let value: String =
+                            module.getattr(py, "value").unwrap().extract(py).unwrap();
+                        assert_eq!(value, "value");
+                    })
+                });
+            }
+        });
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
 }
@@ -5,11 +5,13 @@ use crate::instance::Borrowed;
 use crate::py_result_ext::PyResultExt;
 use crate::types::any::PyAnyMethods;
 use crate::types::bytes::PyBytesMethods;
-use crate::types::PyBytes;
+use crate::types::dict::PyDictMethods;
+use crate::types::{PyBytes, PyDict};
 #[allow(deprecated)]
 use crate::IntoPy;
 use crate::{ffi, Bound, Py, PyAny, PyResult, Python};
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::str;
 
 /// Deprecated alias for [`PyString`].
@@ -268,6 +270,75 @@ pub trait PyStringMethods<'py>: crate::sealed::Sealed {
     /// Encodes this string as a Python `bytes` object, using UTF-8 encoding.
     fn encode_utf8(&self) -> PyResult<Bound<'py, PyBytes>>;
 
+    /// Returns a copy of this string with characters replaced according to `table`.
+    ///
+    /// This is equivalent to the Python expression `self.translate(table)`. Characters mapped to
+    /// `None` are deleted, characters mapped to `Some(c)` are replaced by `c`, and characters not
+    /// present in `table` are left unchanged.
+    ///
+    /// To replace characters by strings of any length, use
+    /// [`translate_str`](PyStringMethods::translate_str).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyString;
+    /// use std::collections::HashMap;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let s = PyString::new(py, "hello, world!");
+    ///     let table = HashMap::from([(',', None), ('!', Some('?'))]);
+    ///     assert_eq!(s.translate(&table)?, "hello world?");
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    fn translate<S>(
+        &self,
+        table: &HashMap<char, Option<char>, S>,
+    ) -> PyResult<Bound<'py, PyString>>;
+
+    /// Returns a copy of this string with characters replaced by strings according to `table`.
+    ///
+    /// This behaves like [`translate`](PyStringMethods::translate), except that characters can
+    /// be replaced by strings of any length.
+    fn translate_str<S>(
+        &self,
+        table: &HashMap<char, Option<String>, S>,
+    ) -> PyResult<Bound<'py, PyString>>;
+
+    /// Returns a copy of this string with `prefix` removed from the start, if present.
+    /// Equivalent to the Python expression `self.removeprefix(prefix)`.
+    ///
+    /// At most one occurrence of `prefix` is removed. If the string does not start with `prefix`,
+    /// or `prefix` is empty, the string is returned unchanged. On Python versions before 3.9,
+    /// which lack `str.removeprefix`, the same behaviour is implemented by PyO3.
+    fn remove_prefix(&self, prefix: &str) -> PyResult<Bound<'py, PyString>>;
+
+    /// Returns a copy of this string with `suffix` removed from the end, if present.
+    /// Equivalent to the Python expression `self.removesuffix(suffix)`.
+    ///
+    /// See [`remove_prefix`](PyStringMethods::remove_prefix) for details.
+    fn remove_suffix(&self, suffix: &str) -> PyResult<Bound<'py, PyString>>;
+
+    /// Returns a copy of this string with tab characters replaced by spaces.
+    /// Equivalent to the Python expression `self.expandtabs(tabsize)`.
+    ///
+    /// Each tab is replaced by enough spaces to reach the next column which is a multiple of
+    /// `tabsize`, with the column counter reset by newlines and carriage returns. A `tabsize` of
+    /// zero removes tabs entirely.
+    fn expandtabs(&self, tabsize: usize) -> PyResult<Bound<'py, PyString>>;
+
+    /// Returns a copy of this string left-filled with ASCII `0` digits to make a string of length
+    /// `width`.
+    /// Equivalent to the Python expression `self.zfill(width)`.
+    ///
+    /// A leading sign prefix (`+` or `-`) is kept at the front, with the padding inserted after it.
+    /// The original string is returned if `width` is less than or equal to its length.
+    fn zfill(&self, width: usize) -> PyResult<Bound<'py, PyString>>;
+
     /// Obtains the raw data backing the Python string.
     ///
     /// If the Python string object was created through legacy APIs, its internal storage format
@@ -284,6 +355,19 @@ pub trait PyStringMethods<'py>: crate::sealed::Sealed {
     /// expected on the targets where you plan to distribute your software.
     #[cfg(not(any(Py_LIMITED_API, GraalPy, PyPy)))]
     unsafe fn data(&self) -> PyResult<PyStringData<'_>>;
+
+    /// Returns whether the Python string is interned, e.g. because it was created with
+    /// [`PyString::intern`].
+    ///
+    /// Interned strings are unique per value, so comparing them (for example as dictionary keys)
+    /// can short-circuit on pointer identity.
+    ///
+    /// # Safety
+    ///
+    /// Like [`data`](PyStringMethods::data), this reads the `interned` field by manually decoding
+    /// a C bitfield, with the same caveats about the targets it is tested on.
+    #[cfg(not(any(Py_LIMITED_API, GraalPy, PyPy)))]
+    unsafe fn is_interned(&self) -> bool;
 }
 
 impl<'py> PyStringMethods<'py> for Bound<'py, PyString> {
@@ -308,10 +392,134 @@ impl<'py> PyStringMethods<'py> for Bound<'py, PyString> {
         }
     }
 
+    fn translate<S>(
+        &self,
+        table: &HashMap<char, Option<char>, S>,
+    ) -> PyResult<Bound<'py, PyString>> {
+        let py_table = PyDict::new(self.py());
+        for (&from, &to) in table {
+            py_table.set_item(u32::from(from), to)?;
+        }
+        translate(self, py_table)
+    }
+
+    fn translate_str<S>(
+        &self,
+        table: &HashMap<char, Option<String>, S>,
+    ) -> PyResult<Bound<'py, PyString>> {
+        let py_table = PyDict::new(self.py());
+        for (&from, to) in table {
+            py_table.set_item(u32::from(from), to.as_deref())?;
+        }
+        translate(self, py_table)
+    }
+
+    fn remove_prefix(&self, prefix: &str) -> PyResult<Bound<'py, PyString>> {
+        #[cfg(Py_3_9)]
+        {
+            remove_affix(self, intern!(self.py(), "removeprefix"), prefix)
+        }
+        #[cfg(not(Py_3_9))]
+        {
+            remove_affix(self, prefix, -1)
+        }
+    }
+
+    fn remove_suffix(&self, suffix: &str) -> PyResult<Bound<'py, PyString>> {
+        #[cfg(Py_3_9)]
+        {
+            remove_affix(self, intern!(self.py(), "removesuffix"), suffix)
+        }
+        #[cfg(not(Py_3_9))]
+        {
+            remove_affix(self, suffix, 1)
+        }
+    }
+
+    fn expandtabs(&self, tabsize: usize) -> PyResult<Bound<'py, PyString>> {
+        self.call_method1(intern!(self.py(), "expandtabs"), (tabsize,))?
+            .downcast_into()
+            .map_err(Into::into)
+    }
+
+    fn zfill(&self, width: usize) -> PyResult<Bound<'py, PyString>> {
+        self.call_method1(intern!(self.py(), "zfill"), (width,))?
+            .downcast_into()
+            .map_err(Into::into)
+    }
+
     #[cfg(not(any(Py_LIMITED_API, GraalPy, PyPy)))]
     unsafe fn data(&self) -> PyResult<PyStringData<'_>> {
         self.as_borrowed().data()
     }
+
+    #[cfg(not(any(Py_LIMITED_API, GraalPy, PyPy)))]
+    unsafe fn is_interned(&self) -> bool {
+        let ascii = self.as_ptr().cast::<ffi::PyASCIIObject>();
+        (*ascii).interned() != ffi::SSTATE_NOT_INTERNED
+    }
+}
+
+#[cfg(Py_3_9)]
+fn remove_affix<'py>(
+    string: &Bound<'py, PyString>,
+    method: &Bound<'py, PyString>,
+    affix: &str,
+) -> PyResult<Bound<'py, PyString>> {
+    string
+        .call_method1(method, (affix,))?
+        .downcast_into()
+        .map_err(Into::into)
+}
+
+/// Removes `affix` from the start (`direction == -1`) or end (`direction == 1`) of `string`.
+#[cfg(not(Py_3_9))]
+fn remove_affix<'py>(
+    string: &Bound<'py, PyString>,
+    affix: &str,
+    direction: std::os::raw::c_int,
+) -> PyResult<Bound<'py, PyString>> {
+    let py = string.py();
+    let affix = PyString::new(py, affix);
+    let matches = unsafe {
+        ffi::PyUnicode_Tailmatch(
+            string.as_ptr(),
+            affix.as_ptr(),
+            0,
+            ffi::PY_SSIZE_T_MAX,
+            direction,
+        )
+    };
+    crate::err::error_on_minusone(py, matches)?;
+    let affix_len = affix.len()?;
+    if matches == 0 || affix_len == 0 {
+        return Ok(string.clone());
+    }
+    let len = string.len()?;
+    let (start, end) = if direction < 0 {
+        (affix_len, len)
+    } else {
+        (0, len - affix_len)
+    };
+    unsafe {
+        ffi::PyUnicode_Substring(
+            string.as_ptr(),
+            start as ffi::Py_ssize_t,
+            end as ffi::Py_ssize_t,
+        )
+        .assume_owned_or_err(py)
+        .downcast_into_unchecked()
+    }
+}
+
+fn translate<'py>(
+    string: &Bound<'py, PyString>,
+    table: Bound<'py, PyDict>,
+) -> PyResult<Bound<'py, PyString>> {
+    string
+        .call_method1(intern!(string.py(), "translate"), (table,))?
+        .downcast_into()
+        .map_err(Into::into)
 }
 
 impl<'a> Borrowed<'a, '_, PyString> {
@@ -639,6 +847,65 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_remove_prefix_and_suffix() {
+        Python::with_gil(|py| {
+            let s = PyString::new(py, "abcabc-défabc");
+            assert_eq!(s.remove_prefix("abc").unwrap(), "abc-défabc");
+            assert_eq!(s.remove_suffix("défabc").unwrap(), "abcabc-");
+            assert_eq!(s.remove_prefix("xyz").unwrap(), "abcabc-défabc");
+            assert_eq!(s.remove_suffix("abcabc").unwrap(), "abcabc-défabc");
+            assert_eq!(s.remove_prefix("").unwrap(), "abcabc-défabc");
+            assert_eq!(s.remove_suffix("").unwrap(), "abcabc-défabc");
+            assert_eq!(s.remove_prefix("abcabc-défabc").unwrap(), "");
+            assert_eq!(
+                s.remove_suffix("longer than abcabc-défabc").unwrap(),
+                "abcabc-défabc"
+            );
+        })
+    }
+
+    #[test]
+    fn test_translate() {
+        Python::with_gil(|py| {
+            let s = PyString::new(py, "a-b_c 🐈");
+            let table = HashMap::from([('-', None), ('_', Some('.')), ('🐈', Some('🐕'))]);
+            assert_eq!(s.translate(&table).unwrap(), "ab.c 🐕");
+
+            let table = HashMap::from([('a', Some("alpha".to_owned())), (' ', None)]);
+            assert_eq!(s.translate_str(&table).unwrap(), "alpha-b_c🐈");
+
+            let table: HashMap<char, Option<char>> = HashMap::new();
+            assert_eq!(s.translate(&table).unwrap(), "a-b_c 🐈");
+        })
+    }
+
+    #[test]
+    fn test_expandtabs() {
+        Python::with_gil(|py| {
+            let s = PyString::new(py, "a\tbc\tdef\n\tx");
+            assert_eq!(s.expandtabs(8).unwrap(), "a       bc      def\n        x");
+            assert_eq!(s.expandtabs(4).unwrap(), "a   bc  def\n    x");
+            assert_eq!(s.expandtabs(1).unwrap(), "a bc def\n x");
+            assert_eq!(s.expandtabs(0).unwrap(), "abcdef\nx");
+
+            let s = PyString::new(py, "no tabs");
+            assert_eq!(s.expandtabs(4).unwrap(), "no tabs");
+        })
+    }
+
+    #[test]
+    fn test_zfill() {
+        Python::with_gil(|py| {
+            assert_eq!(PyString::new(py, "42").zfill(5).unwrap(), "00042");
+            assert_eq!(PyString::new(py, "-7").zfill(4).unwrap(), "-007");
+            assert_eq!(PyString::new(py, "+7").zfill(4).unwrap(), "+007");
+            assert_eq!(PyString::new(py, "123456").zfill(3).unwrap(), "123456");
+            assert_eq!(PyString::new(py, "123").zfill(3).unwrap(), "123");
+            assert_eq!(PyString::new(py, "").zfill(2).unwrap(), "00");
+        })
+    }
+
     #[test]
     fn test_to_string_lossy() {
         Python::with_gil(|py| {
@@ -806,6 +1073,18 @@ mod tests {
         });
     }
 
+    #[test]
+    #[cfg(not(any(Py_LIMITED_API, GraalPy, PyPy)))]
+    fn test_string_is_interned() {
+        Python::with_gil(|py| {
+            let interned = PyString::intern(py, "an interned string");
+            assert!(unsafe { interned.is_interned() });
+
+            let not_interned = PyString::new(py, "a string which is not interned");
+            assert!(!unsafe { not_interned.is_interned() });
+        });
+    }
+
     #[test]
     fn test_py_to_str_utf8() {
         Python::with_gil(|py| {
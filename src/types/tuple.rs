@@ -627,21 +627,10 @@ fn wrong_tuple_length(t: &Bound<'_, PyTuple>, expected_length: usize) -> PyErr {
     exceptions::PyValueError::new_err(msg)
 }
 
-macro_rules! tuple_conversion ({$length:expr,$(($refN:ident, $n:tt, $T:ident)),+} => {
-    #[allow(deprecated)]
-    impl <$($T: ToPyObject),+> ToPyObject for ($($T,)+) {
-        fn to_object(&self, py: Python<'_>) -> PyObject {
-            array_into_tuple(py, [$(self.$n.to_object(py).into_bound(py)),+]).into()
-        }
-    }
-
-    #[allow(deprecated)]
-    impl <$($T: IntoPy<PyObject>),+> IntoPy<PyObject> for ($($T,)+) {
-        fn into_py(self, py: Python<'_>) -> PyObject {
-            array_into_tuple(py, [$(self.$n.into_py(py).into_bound(py)),+]).into()
-        }
-    }
-
+macro_rules! tuple_conversion {
+    // Tuples longer than 12 elements only get the `IntoPyObject` and `FromPyObject` impls, the
+    // deprecated `ToPyObject` and `IntoPy` conversions are not extended to them.
+    (@without_deprecated $length:expr, $(($refN:ident, $n:tt, $T:ident)),+) => {
     impl <'py, $($T),+> IntoPyObject<'py> for ($($T,)+)
     where
         $($T: IntoPyObject<'py>,)+
@@ -937,13 +926,6 @@ macro_rules! tuple_conversion ({$length:expr,$(($refN:ident, $n:tt, $T:ident)),+
         }
     }
 
-    #[allow(deprecated)]
-    impl <$($T: IntoPy<PyObject>),+> IntoPy<Py<PyTuple>> for ($($T,)+) {
-        fn into_py(self, py: Python<'_>) -> Py<PyTuple> {
-            array_into_tuple(py, [$(self.$n.into_py(py).into_bound(py)),+]).unbind()
-        }
-    }
-
     impl<'py, $($T: FromPyObject<'py>),+> FromPyObject<'py> for ($($T,)+) {
         fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self>
         {
@@ -964,14 +946,39 @@ macro_rules! tuple_conversion ({$length:expr,$(($refN:ident, $n:tt, $T:ident)),+
             TypeInfo::Tuple(Some(vec![$( $T::type_input() ),+]))
         }
     }
-});
+    };
+    ($length:expr, $(($refN:ident, $n:tt, $T:ident)),+) => {
+    #[allow(deprecated)]
+    impl <$($T: ToPyObject),+> ToPyObject for ($($T,)+) {
+        fn to_object(&self, py: Python<'_>) -> PyObject {
+            array_into_tuple(py, [$(self.$n.to_object(py).into_bound(py)),+]).into()
+        }
+    }
+
+    #[allow(deprecated)]
+    impl <$($T: IntoPy<PyObject>),+> IntoPy<PyObject> for ($($T,)+) {
+        fn into_py(self, py: Python<'_>) -> PyObject {
+            array_into_tuple(py, [$(self.$n.into_py(py).into_bound(py)),+]).into()
+        }
+    }
+
+    #[allow(deprecated)]
+    impl <$($T: IntoPy<PyObject>),+> IntoPy<Py<PyTuple>> for ($($T,)+) {
+        fn into_py(self, py: Python<'_>) -> Py<PyTuple> {
+            array_into_tuple(py, [$(self.$n.into_py(py).into_bound(py)),+]).unbind()
+        }
+    }
+
+    tuple_conversion!(@without_deprecated $length, $(($refN, $n, $T)),+);
+    };
+}
 
 fn array_into_tuple<'py, const N: usize>(
     py: Python<'py>,
     array: [Bound<'py, PyAny>; N],
 ) -> Bound<'py, PyTuple> {
     unsafe {
-        let ptr = ffi::PyTuple_New(N.try_into().expect("0 < N <= 12"));
+        let ptr = ffi::PyTuple_New(N.try_into().expect("0 < N <= 16"));
         let tup = ptr.assume_owned(py).downcast_into_unchecked();
         for (index, obj) in array.into_iter().enumerate() {
             #[cfg(not(any(Py_LIMITED_API, PyPy, GraalPy)))]
@@ -1087,8 +1094,83 @@ tuple_conversion!(
     (ref11, 11, T11)
 );
 
+tuple_conversion!(
+    @without_deprecated 13,
+    (ref0, 0, T0),
+    (ref1, 1, T1),
+    (ref2, 2, T2),
+    (ref3, 3, T3),
+    (ref4, 4, T4),
+    (ref5, 5, T5),
+    (ref6, 6, T6),
+    (ref7, 7, T7),
+    (ref8, 8, T8),
+    (ref9, 9, T9),
+    (ref10, 10, T10),
+    (ref11, 11, T11),
+    (ref12, 12, T12)
+);
+
+tuple_conversion!(
+    @without_deprecated 14,
+    (ref0, 0, T0),
+    (ref1, 1, T1),
+    (ref2, 2, T2),
+    (ref3, 3, T3),
+    (ref4, 4, T4),
+    (ref5, 5, T5),
+    (ref6, 6, T6),
+    (ref7, 7, T7),
+    (ref8, 8, T8),
+    (ref9, 9, T9),
+    (ref10, 10, T10),
+    (ref11, 11, T11),
+    (ref12, 12, T12),
+    (ref13, 13, T13)
+);
+
+tuple_conversion!(
+    @without_deprecated 15,
+    (ref0, 0, T0),
+    (ref1, 1, T1),
+    (ref2, 2, T2),
+    (ref3, 3, T3),
+    (ref4, 4, T4),
+    (ref5, 5, T5),
+    (ref6, 6, T6),
+    (ref7, 7, T7),
+    (ref8, 8, T8),
+    (ref9, 9, T9),
+    (ref10, 10, T10),
+    (ref11, 11, T11),
+    (ref12, 12, T12),
+    (ref13, 13, T13),
+    (ref14, 14, T14)
+);
+
+tuple_conversion!(
+    @without_deprecated 16,
+    (ref0, 0, T0),
+    (ref1, 1, T1),
+    (ref2, 2, T2),
+    (ref3, 3, T3),
+    (ref4, 4, T4),
+    (ref5, 5, T5),
+    (ref6, 6, T6),
+    (ref7, 7, T7),
+    (ref8, 8, T8),
+    (ref9, 9, T9),
+    (ref10, 10, T10),
+    (ref11, 11, T11),
+    (ref12, 12, T12),
+    (ref13, 13, T13),
+    (ref14, 14, T14),
+    (ref15, 15, T15)
+);
+
 #[cfg(test)]
 mod tests {
+    use crate::exceptions::PyIndexError;
     use crate::types::{any::PyAnyMethods, tuple::PyTupleMethods, PyList, PyTuple};
     use crate::{IntoPyObject, Python};
     use std::collections::HashSet;
@@ -1278,6 +1360,48 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_tuple_lengths_up_to_16() {
+        Python::with_gil(|py| {
+            let t16 = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)
+                .into_pyobject(py)
+                .unwrap();
+            assert_eq!(t16.len(), 16);
+
+            type Tuple16 = (
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+                u8,
+            );
+            // std only implements `PartialEq` and `Debug` for tuples up to length 12
+            let t = t16.extract::<Tuple16>().unwrap();
+            assert_eq!((t.0, t.7, t.15), (0, 7, 15));
+
+            let t13 = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)
+                .into_pyobject(py)
+                .unwrap();
+            let err = t13.extract::<Tuple16>().err().unwrap();
+            assert!(err.is_instance_of::<crate::exceptions::PyValueError>(py));
+            assert_eq!(
+                err.to_string(),
+                "ValueError: expected tuple of length 16, but got tuple of length 13"
+            );
+        });
+    }
+
     #[test]
     fn test_tuple_lengths_up_to_12() {
         Python::with_gil(|py| {
@@ -1620,6 +1744,10 @@ mod tests {
                     .unwrap(),
                 2
             );
+            assert!(tuple
+                .get_borrowed_item(4)
+                .unwrap_err()
+                .is_instance_of::<PyIndexError>(py));
             #[cfg(not(any(Py_LIMITED_API, PyPy, GraalPy)))]
             {
                 assert_eq!(
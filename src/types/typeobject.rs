@@ -1,10 +1,13 @@
 use crate::err::{self, PyResult};
+use crate::exceptions::PyAttributeError;
 use crate::instance::Borrowed;
 #[cfg(not(Py_3_13))]
 use crate::pybacked::PyBackedStr;
 use crate::types::any::PyAnyMethods;
-use crate::types::PyTuple;
-use crate::{ffi, Bound, PyAny, PyTypeInfo, Python};
+use crate::types::iter::BoundTupleIterator;
+use crate::types::{PyDict, PyDictMethods, PyList, PyTuple, PyTupleMethods};
+use crate::{ffi, Bound, IntoPyObject, PyAny, PyTypeInfo, Python};
+use std::iter::FusedIterator;
 
 use super::PyString;
 
@@ -50,6 +53,139 @@ impl PyType {
             .downcast_unchecked()
             .to_owned()
     }
+
+    /// Returns a builder for creating a new class at runtime.
+    ///
+    /// This is equivalent to calling `type(name, bases, namespace)` in Python, but assembles the
+    /// bases and namespace from Rust values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyType;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let point = PyType::builder(py, "Point")
+    ///         .slots(&["x", "y"])
+    ///         .dict_item("dimensions", 2)?
+    ///         .build()?;
+    ///
+    ///     let p = point.call0()?;
+    ///     p.setattr("x", 1)?;
+    ///     assert!(p.setattr("z", 1).is_err());
+    ///     assert_eq!(p.getattr("dimensions")?.extract::<u32>()?, 2);
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    pub fn builder<'py>(py: Python<'py>, name: &str) -> PyTypeBuilder<'py> {
+        PyTypeBuilder {
+            name: PyString::new(py, name),
+            bases: Vec::new(),
+            namespace: PyDict::new(py),
+            slots: None,
+            metaclass: None,
+        }
+    }
+}
+
+/// Iterator over the method resolution order of a type, created by
+/// [`PyTypeMethods::iter_mro`].
+pub struct BoundMroIterator<'py> {
+    inner: BoundTupleIterator<'py>,
+}
+
+impl<'py> Iterator for BoundMroIterator<'py> {
+    type Item = Bound<'py, PyType>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // Safety: every entry of `__mro__` is a type object
+        self.inner
+            .next()
+            .map(|ty| unsafe { ty.downcast_into_unchecked() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for BoundMroIterator<'_> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl FusedIterator for BoundMroIterator<'_> {}
+
+/// Builder for creating a new class at runtime, created by [`PyType::builder`].
+#[must_use]
+pub struct PyTypeBuilder<'py> {
+    name: Bound<'py, PyString>,
+    bases: Vec<Bound<'py, PyType>>,
+    namespace: Bound<'py, PyDict>,
+    slots: Option<Vec<String>>,
+    metaclass: Option<Bound<'py, PyType>>,
+}
+
+impl<'py> PyTypeBuilder<'py> {
+    /// Sets the base classes of the new class.
+    ///
+    /// If no bases are given, the class derives from `object`.
+    pub fn bases(mut self, bases: &[&Bound<'py, PyType>]) -> Self {
+        self.bases = bases.iter().map(|&base| base.clone()).collect();
+        self
+    }
+
+    /// Adds an item to the namespace of the new class, e.g. a class attribute or method.
+    pub fn dict_item<K, V>(self, key: K, value: V) -> PyResult<Self>
+    where
+        K: IntoPyObject<'py, Target = PyString>,
+        V: IntoPyObject<'py>,
+    {
+        self.namespace.set_item(key, value)?;
+        Ok(self)
+    }
+
+    /// Adds all items of `dict` to the namespace of the new class.
+    pub fn dict(self, dict: &Bound<'py, PyDict>) -> PyResult<Self> {
+        self.namespace.update(dict.as_mapping())?;
+        Ok(self)
+    }
+
+    /// Sets the `__slots__` of the new class, which restricts its instances to these attributes.
+    pub fn slots(mut self, slots: &[&str]) -> Self {
+        self.slots = Some(slots.iter().map(|&slot| slot.to_owned()).collect());
+        self
+    }
+
+    /// Sets the metaclass used to create the new class, instead of `type`.
+    pub fn metaclass<M: PyTypeInfo>(mut self) -> Self {
+        self.metaclass = Some(M::type_object(self.name.py()));
+        self
+    }
+
+    /// Creates the new class.
+    ///
+    /// This calls the metaclass with the name, bases and namespace, and fails if the call raises
+    /// or returns an object which is not a type.
+    pub fn build(self) -> PyResult<Bound<'py, PyType>> {
+        let py = self.name.py();
+        if let Some(slots) = self.slots {
+            self.namespace
+                .set_item(intern!(py, "__slots__"), PyTuple::new(py, slots)?)?;
+        }
+        let bases = PyTuple::new(py, self.bases)?;
+        let metaclass = self.metaclass.unwrap_or_else(|| py.get_type::<PyType>());
+        metaclass
+            .call1((self.name, bases, self.namespace))?
+            .downcast_into()
+            .map_err(Into::into)
+    }
 }
 
 /// Implementation of functionality for [`PyType`].
@@ -93,10 +229,49 @@ pub trait PyTypeMethods<'py>: crate::sealed::Sealed {
     /// Equivalent to the Python expression `self.__mro__`.
     fn mro(&self) -> Bound<'py, PyTuple>;
 
+    /// Returns an iterator over the method resolution order of this type.
+    ///
+    /// This is equivalent to iterating `self.__mro__`, with each entry yielded as a `PyType`.
+    fn iter_mro(&self) -> BoundMroIterator<'py>;
+
     /// Return Python bases
     ///
     /// Equivalent to the Python expression `self.__bases__`.
     fn bases(&self) -> Bound<'py, PyTuple>;
+
+    /// Returns the `tp_flags` of this type.
+    ///
+    /// The individual flags are defined as `Py_TPFLAGS_*` constants in the [`ffi`] module.
+    fn flags(&self) -> u64;
+
+    /// Returns whether this type is an abstract base class with unimplemented abstract methods,
+    /// which cannot be instantiated.
+    ///
+    /// This checks the `Py_TPFLAGS_IS_ABSTRACT` flag.
+    fn is_abstract(&self) -> bool;
+
+    /// Returns whether this type can be subclassed.
+    ///
+    /// This checks the `Py_TPFLAGS_BASETYPE` flag.
+    fn is_base_type(&self) -> bool;
+
+    /// Returns whether instances of this type are tracked by the garbage collector.
+    ///
+    /// This checks the `Py_TPFLAGS_HAVE_GC` flag.
+    fn has_gc(&self) -> bool;
+
+    /// Returns the names of the abstract methods which prevent this type from being instantiated,
+    /// in sorted order.
+    ///
+    /// This reads the `__abstractmethods__` attribute set by `abc.ABCMeta`. Types without that
+    /// attribute, and concrete types, return an empty list.
+    fn abstract_methods(&self) -> PyResult<Vec<String>>;
+
+    /// Returns the direct subclasses of this type which are still alive.
+    ///
+    /// Equivalent to the Python expression `self.__subclasses__()`. Subclasses are only tracked
+    /// weakly by their base, so types which have been garbage collected are not included.
+    fn subclasses(&self) -> PyResult<Bound<'py, PyList>>;
 }
 
 impl<'py> PyTypeMethods<'py> for Bound<'py, PyType> {
@@ -226,6 +401,12 @@ impl<'py> PyTypeMethods<'py> for Bound<'py, PyType> {
         mro
     }
 
+    fn iter_mro(&self) -> BoundMroIterator<'py> {
+        BoundMroIterator {
+            inner: self.mro().iter(),
+        }
+    }
+
     fn bases(&self) -> Bound<'py, PyTuple> {
         #[cfg(any(Py_LIMITED_API, PyPy))]
         let bases = self
@@ -246,14 +427,72 @@ impl<'py> PyTypeMethods<'py> for Bound<'py, PyType> {
 
         bases
     }
+
+    // `c_ulong` is only 32 bits on some platforms
+    #[allow(clippy::unnecessary_cast)]
+    fn flags(&self) -> u64 {
+        #[cfg(Py_LIMITED_API)]
+        let flags = unsafe { ffi::PyType_GetFlags(self.as_type_ptr()) };
+
+        #[cfg(all(not(Py_LIMITED_API), Py_GIL_DISABLED))]
+        let flags = unsafe {
+            (*self.as_type_ptr())
+                .tp_flags
+                .load(std::sync::atomic::Ordering::Relaxed)
+        };
+
+        #[cfg(all(not(Py_LIMITED_API), not(Py_GIL_DISABLED)))]
+        let flags = unsafe { (*self.as_type_ptr()).tp_flags };
+
+        flags as u64
+    }
+
+    #[allow(clippy::unnecessary_cast)]
+    fn is_abstract(&self) -> bool {
+        self.flags() & ffi::Py_TPFLAGS_IS_ABSTRACT as u64 != 0
+    }
+
+    #[allow(clippy::unnecessary_cast)]
+    fn is_base_type(&self) -> bool {
+        self.flags() & ffi::Py_TPFLAGS_BASETYPE as u64 != 0
+    }
+
+    #[allow(clippy::unnecessary_cast)]
+    fn has_gc(&self) -> bool {
+        self.flags() & ffi::Py_TPFLAGS_HAVE_GC as u64 != 0
+    }
+
+    fn abstract_methods(&self) -> PyResult<Vec<String>> {
+        let py = self.py();
+        let abstract_methods = match self.getattr(intern!(py, "__abstractmethods__")) {
+            Ok(abstract_methods) => abstract_methods,
+            Err(err) if err.is_instance_of::<PyAttributeError>(py) => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut names = abstract_methods
+            .try_iter()?
+            .map(|name| name?.extract())
+            .collect::<PyResult<Vec<String>>>()?;
+        names.sort();
+        Ok(names)
+    }
+
+    fn subclasses(&self) -> PyResult<Bound<'py, PyList>> {
+        self.call_method0(intern!(self.py(), "__subclasses__"))?
+            .downcast_into()
+            .map_err(Into::into)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::exceptions::PyAttributeError;
     use crate::tests::common::generate_unique_module_name;
-    use crate::types::{PyAnyMethods, PyBool, PyInt, PyModule, PyTuple, PyType, PyTypeMethods};
-    use crate::PyAny;
-    use crate::Python;
+    use crate::types::{
+        IntoPyDict, PyAnyMethods, PyBool, PyInt, PyList, PyListMethods, PyModule, PyTuple, PyType,
+        PyTypeMethods,
+    };
+    use crate::{Bound, PyAny, Python};
     use pyo3_ffi::c_str;
 
     #[test]
@@ -291,6 +530,22 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_iter_mro() {
+        Python::with_gil(|py| {
+            let bool_type = py.get_type::<PyBool>();
+            let mro = bool_type.iter_mro();
+            assert_eq!(mro.len(), 3);
+            let mro: Vec<_> = mro.collect();
+            assert!(mro[0].is(&bool_type));
+            assert!(mro[1].is(&py.get_type::<PyInt>()));
+            assert!(mro[2].is(&py.get_type::<PyAny>()));
+
+            let object = py.get_type::<PyAny>();
+            assert_eq!(object.iter_mro().count(), 1);
+        });
+    }
+
     #[test]
     fn test_bases_bool() {
         Python::with_gil(|py| {
@@ -313,6 +568,203 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_type_flags() {
+        Python::with_gil(|py| {
+            let object = py.get_type::<PyAny>();
+            assert!(object.is_base_type());
+            assert!(!object.is_abstract());
+            assert!(!object.has_gc());
+
+            let bool_type = py.get_type::<PyBool>();
+            assert!(!bool_type.is_base_type());
+
+            let list_type = py.get_type::<PyList>();
+            assert!(list_type.is_base_type());
+            assert!(list_type.has_gc());
+
+            let sequence = py
+                .import("collections.abc")
+                .unwrap()
+                .getattr("Sequence")
+                .unwrap()
+                .downcast_into::<PyType>()
+                .unwrap();
+            assert!(sequence.is_abstract());
+        });
+    }
+
+    #[test]
+    fn test_type_builder() {
+        Python::with_gil(|py| {
+            let base = PyType::builder(py, "Base")
+                .dict_item("greeting", "hello")
+                .unwrap()
+                .build()
+                .unwrap();
+            assert_eq!(base.name().unwrap(), "Base");
+            assert!(base.bases().eq((py.get_type::<PyAny>(),)).unwrap());
+
+            let namespace = [("answer", 42)].into_py_dict(py).unwrap();
+            let derived = PyType::builder(py, "Derived")
+                .bases(&[&base])
+                .dict(&namespace)
+                .unwrap()
+                .slots(&["x"])
+                .metaclass::<PyType>()
+                .build()
+                .unwrap();
+            assert!(derived.is_subclass(&base).unwrap());
+            assert!(derived.get_type().is(&py.get_type::<PyType>()));
+
+            let instance = derived.call0().unwrap();
+            assert_eq!(
+                instance
+                    .getattr("greeting")
+                    .unwrap()
+                    .extract::<String>()
+                    .unwrap(),
+                "hello"
+            );
+            assert_eq!(
+                instance
+                    .getattr("answer")
+                    .unwrap()
+                    .extract::<i32>()
+                    .unwrap(),
+                42
+            );
+            instance.setattr("x", 1).unwrap();
+            // `Base` has no `__slots__`, so instances still have a `__dict__`
+            instance.setattr("y", 2).unwrap();
+
+            let slotted = PyType::builder(py, "Slotted")
+                .slots(&["x"])
+                .build()
+                .unwrap()
+                .call0()
+                .unwrap();
+            slotted.setattr("x", 1).unwrap();
+            assert!(slotted
+                .setattr("y", 2)
+                .unwrap_err()
+                .is_instance_of::<PyAttributeError>(py));
+
+            // `int` is not a metaclass
+            assert!(PyType::builder(py, "Invalid")
+                .metaclass::<PyInt>()
+                .build()
+                .is_err());
+        });
+    }
+
+    #[test]
+    fn test_abstract_methods() {
+        Python::with_gil(|py| {
+            let module = PyModule::from_code(
+                py,
+                c_str!(
+                    r#"
+import abc
+
+class Shape(abc.ABC):
+    @abc.abstractmethod
+    def perimeter(self): ...
+
+    @abc.abstractmethod
+    def area(self): ...
+
+class Square(Shape):
+    def perimeter(self):
+        return 4
+
+class UnitSquare(Square):
+    def area(self):
+        return 1
+"#
+                ),
+                c_str!(file!()),
+                &generate_unique_module_name("test_module"),
+            )
+            .expect("module create failed");
+
+            let abstract_methods = |name: &str| {
+                module
+                    .getattr(name)
+                    .unwrap()
+                    .downcast_into::<PyType>()
+                    .unwrap()
+                    .abstract_methods()
+                    .unwrap()
+            };
+            assert_eq!(abstract_methods("Shape"), ["area", "perimeter"]);
+            assert_eq!(abstract_methods("Square"), ["area"]);
+            assert!(abstract_methods("UnitSquare").is_empty());
+
+            // `__abstractmethods__` is absent on regular types
+            assert!(py
+                .get_type::<PyInt>()
+                .abstract_methods()
+                .unwrap()
+                .is_empty());
+            assert!(py
+                .get_type::<PyType>()
+                .abstract_methods()
+                .unwrap()
+                .is_empty());
+        });
+    }
+
+    #[test]
+    fn test_subclasses() {
+        Python::with_gil(|py| {
+            let module = PyModule::from_code(
+                py,
+                c_str!(
+                    r#"
+class Base: ...
+class A(Base): ...
+class B(Base): ...
+class C(A): ...
+
+def make_temporary():
+    class Temporary(Base): ...
+"#
+                ),
+                c_str!(file!()),
+                &generate_unique_module_name("test_module"),
+            )
+            .expect("module create failed");
+
+            let base = module
+                .getattr("Base")
+                .unwrap()
+                .downcast_into::<PyType>()
+                .unwrap();
+            let names = |ty: &Bound<'_, PyType>| {
+                ty.subclasses()
+                    .unwrap()
+                    .iter()
+                    .map(|cls| {
+                        cls.downcast::<PyType>()
+                            .unwrap()
+                            .name()
+                            .unwrap()
+                            .to_string()
+                    })
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(names(&base), ["A", "B"]);
+
+            // Subclasses which have been collected are not returned
+            module.getattr("make_temporary").unwrap().call0().unwrap();
+            py.import("gc").unwrap().call_method0("collect").unwrap();
+            assert_eq!(names(&base), ["A", "B"]);
+
+            assert!(py.get_type::<PyBool>().subclasses().unwrap().is_empty());
+        });
+    }
+
     #[test]
     fn test_type_names_standard() {
         Python::with_gil(|py| {
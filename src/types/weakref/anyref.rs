@@ -1,11 +1,12 @@
 use crate::err::PyResult;
 use crate::ffi_ptr_ext::FfiPtrExt;
+use crate::sync::GILOnceCell;
 use crate::type_object::{PyTypeCheck, PyTypeInfo};
 use crate::types::{
     any::{PyAny, PyAnyMethods},
-    PyNone,
+    PyList, PyNone,
 };
-use crate::{ffi, Bound, Python};
+use crate::{ffi, Bound, Py, Python};
 
 /// Represents any Python `weakref` reference.
 ///
@@ -27,6 +28,62 @@ impl PyTypeCheck for PyWeakref {
     }
 }
 
+impl PyWeakref {
+    /// Returns a list of all weak references and proxies which refer to `object`.
+    ///
+    /// This is equivalent to the Python expression `weakref.getweakrefs(object)`.
+    ///
+    /// # Example
+    #[cfg_attr(
+        not(all(feature = "macros", not(all(Py_LIMITED_API, not(Py_3_9))))),
+        doc = "```rust,ignore"
+    )]
+    #[cfg_attr(
+        all(feature = "macros", not(all(Py_LIMITED_API, not(Py_3_9)))),
+        doc = "```rust"
+    )]
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::{PyWeakref, PyWeakrefReference};
+    ///
+    /// #[pyclass(weakref)]
+    /// struct Foo { /* fields omitted */ }
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let object = Bound::new(py, Foo {})?;
+    ///     assert_eq!(PyWeakref::weakref_count(object.as_any())?, 0);
+    ///
+    ///     let reference = PyWeakrefReference::new(&object)?;
+    ///     let refs = PyWeakref::get_weakrefs(object.as_any())?;
+    ///     assert_eq!(refs.len(), 1);
+    ///     assert!(refs.get_item(0)?.is(&reference));
+    ///     assert_eq!(PyWeakref::weakref_count(object.as_any())?, 1);
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    pub fn get_weakrefs<'py>(object: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyList>> {
+        static GET_WEAKREFS: GILOnceCell<Py<PyAny>> = GILOnceCell::new();
+        GET_WEAKREFS
+            .import(object.py(), "weakref", "getweakrefs")?
+            .call1((object,))?
+            .downcast_into()
+            .map_err(Into::into)
+    }
+
+    /// Returns the number of weak references and proxies which refer to `object`.
+    ///
+    /// This is equivalent to the Python expression `weakref.getweakrefcount(object)`.
+    /// Objects which do not support weak references always report a count of zero.
+    pub fn weakref_count(object: &Bound<'_, PyAny>) -> PyResult<usize> {
+        static GET_WEAKREF_COUNT: GILOnceCell<Py<PyAny>> = GILOnceCell::new();
+        GET_WEAKREF_COUNT
+            .import(object.py(), "weakref", "getweakrefcount")?
+            .call1((object,))?
+            .extract()
+    }
+}
+
 /// Implementation of functionality for [`PyWeakref`].
 ///
 /// These methods are defined for the `Bound<'py, PyWeakref>` smart pointer, so to use method call
@@ -322,6 +379,49 @@ pub trait PyWeakrefMethods<'py>: crate::sealed::Sealed {
     /// [`weakref.ref`]: https://docs.python.org/3/library/weakref.html#weakref.ref
     fn upgrade(&self) -> Option<Bound<'py, PyAny>>;
 
+    /// Returns `true` if the object this weakref refers to no longer exists.
+    ///
+    /// This is equivalent to `self.upgrade().is_none()`.
+    ///
+    /// # Example
+    #[cfg_attr(
+        not(all(feature = "macros", not(all(Py_LIMITED_API, not(Py_3_9))))),
+        doc = "```rust,ignore"
+    )]
+    #[cfg_attr(
+        all(feature = "macros", not(all(Py_LIMITED_API, not(Py_3_9)))),
+        doc = "```rust"
+    )]
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyWeakrefReference;
+    ///
+    /// #[pyclass(weakref)]
+    /// struct Foo { /* fields omitted */ }
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let data = Bound::new(py, Foo{})?;
+    ///     let reference = PyWeakrefReference::new(&data)?;
+    ///     assert!(reference.is_alive());
+    ///
+    ///     drop(data);
+    ///
+    ///     assert!(reference.is_dead());
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    fn is_dead(&self) -> bool {
+        self.upgrade().is_none()
+    }
+
+    /// Returns `true` if the object this weakref refers to still exists.
+    ///
+    /// This is the inverse of [`is_dead`](PyWeakrefMethods::is_dead).
+    fn is_alive(&self) -> bool {
+        !self.is_dead()
+    }
+
     /// Retrieve to a Bound object pointed to by the weakref.
     ///
     /// This function returns `Bound<'py, PyAny>`, which is either the object if it still exists, otherwise it will refer to [`PyNone`](crate::types::PyNone).
@@ -421,7 +521,8 @@ mod tests {
     mod python_class {
         use super::*;
         use crate::ffi;
-        use crate::{py_result_ext::PyResultExt, types::PyType};
+        use crate::types::{PyListMethods, PyType};
+        use crate::{py_result_ext::PyResultExt, IntoPyObject};
 
         fn get_type(py: Python<'_>) -> PyResult<Bound<'_, PyType>> {
             py.run(ffi::c_str!("class A:\n    pass\n"), None, None)?;
@@ -533,10 +634,15 @@ mod tests {
                     assert!(reference.upgrade().is_some());
                     assert!(reference.upgrade().map_or(false, |obj| obj.is(&object)));
 
+                    assert!(reference.is_alive());
+                    assert!(!reference.is_dead());
+
                     drop(object);
 
                     assert!(not_call_retrievable || reference.call0()?.is_none());
                     assert!(reference.upgrade().is_none());
+                    assert!(!reference.is_alive());
+                    assert!(reference.is_dead());
 
                     Ok(())
                 })
@@ -579,6 +685,36 @@ mod tests {
             inner(new_reference, true)?;
             inner(new_proxy, false)
         }
+
+        #[test]
+        fn test_get_weakrefs() -> PyResult<()> {
+            Python::with_gil(|py| {
+                let class = get_type(py)?;
+                let object = class.call0()?;
+                assert_eq!(PyWeakref::weakref_count(&object)?, 0);
+                assert!(PyWeakref::get_weakrefs(&object)?.is_empty());
+
+                let reference = new_reference(&object)?;
+                let proxy = new_proxy(&object)?;
+                assert_eq!(PyWeakref::weakref_count(&object)?, 2);
+
+                let refs = PyWeakref::get_weakrefs(&object)?;
+                assert_eq!(refs.len(), 2);
+                assert!(refs.iter().any(|r| r.is(&reference)));
+                assert!(refs.iter().any(|r| r.is(&proxy)));
+
+                drop(refs);
+                drop(reference);
+                assert_eq!(PyWeakref::weakref_count(&object)?, 1);
+
+                // objects which cannot be weakly referenced have no weakrefs
+                let int = 5i32.into_pyobject(py)?;
+                assert_eq!(PyWeakref::weakref_count(&int)?, 0);
+                assert!(PyWeakref::get_weakrefs(&int)?.is_empty());
+
+                Ok(())
+            })
+        }
     }
 
     // under 'abi3-py37' and 'abi3-py38' PyClass cannot be weakreferencable.
@@ -685,10 +821,15 @@ mod tests {
                     assert!(reference.upgrade().is_some());
                     assert!(reference.upgrade().map_or(false, |obj| obj.is(&object)));
 
+                    assert!(reference.is_alive());
+                    assert!(!reference.is_dead());
+
                     drop(object);
 
                     assert!(not_call_retrievable || reference.call0()?.is_none());
                     assert!(reference.upgrade().is_none());
+                    assert!(!reference.is_alive());
+                    assert!(reference.is_dead());
 
                     Ok(())
                 })
@@ -11,6 +11,11 @@ use super::PyWeakrefMethods;
 ///
 /// In Python this is created by calling `weakref.proxy`.
 /// This is either a `weakref.ProxyType` or a `weakref.CallableProxyType` (`weakref.ProxyTypes`).
+///
+/// A proxy transparently forwards attribute access and other operations to its referent, and
+/// raises `ReferenceError` once the referent has been garbage collected. To get hold of the
+/// referent itself, use the methods of [`PyWeakrefMethods`], such as
+/// [`upgrade`](PyWeakrefMethods::upgrade).
 #[repr(transparent)]
 pub struct PyWeakrefProxy(PyAny);
 
@@ -28,7 +33,6 @@ impl PyTypeCheck for PyWeakrefProxy {
     }
 }
 
-/// TODO: UPDATE DOCS
 impl PyWeakrefProxy {
     /// Constructs a new Weak Reference (`weakref.proxy`/`weakref.ProxyType`/`weakref.CallableProxyType`) for the given object.
     ///
@@ -9,7 +9,7 @@ use pyo3::types::IntoPyDict;
 use std::ffi::CString;
 use std::os::raw::{c_int, c_void};
 use std::ptr;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 
 #[path = "../src/tests/common.rs"]
@@ -137,6 +137,65 @@ fn test_releasebuffer_unraisable_error() {
     });
 }
 
+#[test]
+fn test_frozen_class_writable_buffer() {
+    #[pyclass(frozen)]
+    struct FrozenBuffer {
+        data: Vec<u8>,
+        getbuffer_calls: AtomicUsize,
+    }
+
+    #[pymethods]
+    impl FrozenBuffer {
+        unsafe fn __getbuffer__(
+            slf: Bound<'_, Self>,
+            view: *mut ffi::Py_buffer,
+            flags: c_int,
+        ) -> PyResult<()> {
+            let this = slf.get();
+            this.getbuffer_calls.fetch_add(1, Ordering::Relaxed);
+            // extend the lifetime of the data to that of `slf`, which owns it
+            let data = std::slice::from_raw_parts(this.data.as_ptr(), this.data.len());
+            fill_view_from_readonly_data(view, flags, data, slf.into_any())
+        }
+
+        unsafe fn __releasebuffer__(&self, view: *mut ffi::Py_buffer) {
+            drop(CString::from_raw((*view).format));
+        }
+    }
+
+    Python::with_gil(|py| {
+        let instance = Bound::new(
+            py,
+            FrozenBuffer {
+                data: b"abc".to_vec(),
+                getbuffer_calls: AtomicUsize::new(0),
+            },
+        )
+        .unwrap();
+        py_assert!(py, instance, "bytes(memoryview(instance)) == b'abc'");
+        assert_eq!(instance.get().getbuffer_calls.load(Ordering::Relaxed), 1);
+
+        // a writable buffer is refused before `__getbuffer__` is called
+        let mut view = std::mem::MaybeUninit::<ffi::Py_buffer>::uninit();
+        let result = unsafe {
+            ffi::PyObject_GetBuffer(
+                instance.as_ptr(),
+                view.as_mut_ptr(),
+                ffi::PyBUF_WRITABLE | ffi::PyBUF_FORMAT,
+            )
+        };
+        assert_eq!(result, -1);
+        let err = PyErr::fetch(py);
+        assert!(err.is_instance_of::<PyBufferError>(py));
+        assert_eq!(
+            err.to_string(),
+            "BufferError: cannot export a writable buffer from frozen class 'FrozenBuffer'"
+        );
+        assert_eq!(instance.get().getbuffer_calls.load(Ordering::Relaxed), 1);
+    });
+}
+
 /// # Safety
 ///
 /// `view` must be a valid pointer to ffi::Py_buffer, or null
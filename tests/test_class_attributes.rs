@@ -180,6 +180,26 @@ impl StructWithRenamedFields {
             fourth_field: false,
         }
     }
+
+    #[getter]
+    fn computed_field(&self) -> u8 {
+        self.second_field * 2
+    }
+
+    #[getter]
+    fn get_doubled_field(&self) -> u8 {
+        self.second_field * 2
+    }
+
+    #[setter]
+    fn set_doubled_field(&mut self, value: u8) {
+        self.second_field = value / 2;
+    }
+
+    #[getter(explicit_field)]
+    fn explicitly_named_field(&self) -> bool {
+        self.first_field
+    }
 }
 
 #[test]
@@ -198,6 +218,13 @@ fn test_renaming_all_struct_fields() {
             .setattr("third_field", PyBool::new(py, true))
             .is_ok());
         py_assert!(py, struct_obj, "struct_obj.third_field == True");
+        py_assert!(py, struct_obj, "struct_obj.computedField == 10");
+        py_assert!(py, struct_obj, "not hasattr(struct_obj, 'computed_field')");
+        assert!(struct_obj.setattr("doubledField", 8).is_ok());
+        py_assert!(py, struct_obj, "struct_obj.doubledField == 8");
+        py_assert!(py, struct_obj, "struct_obj.secondField == 4");
+        py_assert!(py, struct_obj, "struct_obj.explicit_field == False");
+        py_assert!(py, struct_obj, "not hasattr(struct_obj, 'explicitField')");
     });
 }
 
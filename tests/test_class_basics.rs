@@ -202,6 +202,23 @@ fn empty_class_in_module() {
     });
 }
 
+#[pyclass(module = "my_pkg.sub")]
+struct ClassWithDottedModule {}
+
+#[test]
+fn class_with_dotted_module() {
+    Python::with_gil(|py| {
+        let ty = py.get_type::<ClassWithDottedModule>();
+        py_assert!(py, ty, "ty.__module__ == 'my_pkg.sub'");
+        py_assert!(py, ty, "ty.__qualname__ == 'ClassWithDottedModule'");
+        py_assert!(
+            py,
+            ty,
+            "repr(ty) == \"<class 'my_pkg.sub.ClassWithDottedModule'>\""
+        );
+    });
+}
+
 #[pyclass]
 struct ClassWithObjectField {
     // It used to be that PyObject was not supported with (get, set)
@@ -256,6 +273,31 @@ fn class_with_hash() {
     });
 }
 
+#[test]
+fn class_with_hash_as_dict_key() {
+    Python::with_gil(|py| {
+        let a = Py::new(py, ClassWithHash { value: 1 }).unwrap();
+        let b = Py::new(py, ClassWithHash { value: 1 }).unwrap();
+        let c = Py::new(py, ClassWithHash { value: 2 }).unwrap();
+
+        py_run!(
+            py,
+            a b c,
+            r#"
+            assert a is not b
+            assert a == b and hash(a) == hash(b)
+            assert a != c
+            d = {a: "first"}
+            d[b] = "second"
+            d[c] = "third"
+            assert len(d) == 2
+            assert d[a] == "second"
+            assert len({a, b, c}) == 2
+            "#
+        );
+    });
+}
+
 #[pyclass(unsendable, subclass)]
 struct UnsendableBase {
     value: std::rc::Rc<usize>,
@@ -670,6 +712,81 @@ fn drop_unsendable_elsewhere() {
     });
 }
 
+#[test]
+#[cfg(all(Py_3_8, not(Py_GIL_DISABLED)))] // sys.unraisablehook not available until Python 3.8
+#[cfg_attr(target_arch = "wasm32", ignore)]
+fn leak_unsendable_dropped_elsewhere() {
+    use common::{CatchWarnings, UnraisableCapture};
+    use pyo3::exceptions::PyRuntimeWarning;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use std::thread::spawn;
+
+    #[pyclass(unsendable, leak_on_foreign_drop)]
+    struct Unsendable {
+        dropped: Arc<AtomicUsize>,
+    }
+
+    impl Drop for Unsendable {
+        fn drop(&mut self) {
+            self.dropped.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    Python::with_gil(|py| {
+        let capture = UnraisableCapture::install(py);
+
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let new_unsendable = || {
+            Py::new(
+                py,
+                Unsendable {
+                    dropped: dropped.clone(),
+                },
+            )
+            .unwrap()
+        };
+        let unsendables = [new_unsendable(), new_unsendable()];
+
+        CatchWarnings::enter(py, |w| {
+            py.allow_threads(|| {
+                spawn(move || {
+                    Python::with_gil(move |_py| {
+                        drop(unsendables);
+                    });
+                })
+                .join()
+                .unwrap();
+            });
+
+            // only the first leak is reported
+            assert_eq!(w.len(), 1);
+            let warning = w.get_item(0)?;
+            assert!(warning
+                .getattr("category")?
+                .is(&py.get_type::<PyRuntimeWarning>()));
+            assert!(warning
+                .getattr("message")?
+                .str()?
+                .to_str()?
+                .starts_with("test_class_basics::leak_unsendable_dropped_elsewhere::Unsendable is unsendable, but is being dropped on another thread; leaking it"));
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(dropped.load(Ordering::SeqCst), 0);
+        assert!(capture.borrow_mut(py).capture.is_none());
+
+        // dropping on the owning thread still runs `Drop`
+        drop(new_unsendable());
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+
+        capture.borrow_mut(py).uninstall(py);
+    });
+}
+
 #[test]
 #[cfg(any(Py_3_9, not(Py_LIMITED_API)))]
 fn test_unsendable_dict() {
@@ -714,3 +831,34 @@ fn test_unsendable_dict_with_weakref() {
         );
     });
 }
+
+#[pyclass(match_args(x, y), rename_all = "UPPERCASE")]
+struct MatchArgsPoint {
+    #[pyo3(get)]
+    x: i32,
+    #[pyo3(get, name = "why")]
+    y: i32,
+    #[allow(dead_code)]
+    z: i32,
+}
+
+#[test]
+#[cfg(Py_3_10)]
+fn match_args() {
+    Python::with_gil(|py| {
+        let point = Py::new(py, MatchArgsPoint { x: 1, y: 2, z: 3 }).unwrap();
+        let cls = py.get_type::<MatchArgsPoint>();
+        py_run!(
+            py,
+            point cls,
+            r#"
+assert cls.__match_args__ == ("X", "why")
+match point:
+    case cls(a, b):
+        assert (a, b) == (1, 2)
+    case _:
+        assert False
+"#
+        );
+    });
+}
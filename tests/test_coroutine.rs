@@ -323,6 +323,60 @@ fn test_async_method_receiver() {
     assert!(IS_DROPPED.load(Ordering::SeqCst));
 }
 
+#[test]
+fn test_async_method_send_and_close() {
+    static FUTURE_DROPPED: AtomicBool = AtomicBool::new(false);
+
+    struct DropGuard;
+
+    impl Drop for DropGuard {
+        fn drop(&mut self) {
+            FUTURE_DROPPED.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[pyclass]
+    struct Service;
+    #[pymethods]
+    impl Service {
+        async fn ready(&self, value: usize) -> usize {
+            value * 2
+        }
+        async fn never(&self) {
+            let _guard = DropGuard;
+            poll_fn(|_| Poll::<()>::Pending).await;
+        }
+    }
+
+    Python::with_gil(|gil| {
+        let service = Bound::new(gil, Service).unwrap();
+        let test = r#"
+        # a future which resolves immediately completes on the first `send`
+        coro = service.ready(21)
+        try:
+            coro.send(None)
+        except StopIteration as stop:
+            assert stop.value == 42
+        else:
+            assert False
+
+        # closing a suspended coroutine drops the Rust future; suspending requires a running
+        # event loop to wake the coroutine up again
+        import asyncio
+
+        async def main(service):
+            coro = service.never()
+            coro.send(None)
+            coro.close()
+
+        asyncio.run(main(service))
+        "#;
+        py_run!(gil, service, test);
+    });
+
+    assert!(FUTURE_DROPPED.load(Ordering::SeqCst));
+}
+
 #[test]
 fn test_async_method_receiver_with_other_args() {
     #[pyclass]
@@ -0,0 +1,61 @@
+#![cfg(feature = "macros")]
+
+use std::sync::{Arc, Mutex};
+
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::ffi::c_str;
+use pyo3::prelude::*;
+use pyo3::py_run;
+
+// `sys.excepthook` is process-wide state, so this test runs separately to avoid changing how
+// uncaught exceptions are reported in other tests.
+
+#[test]
+fn rust_excepthook_receives_uncaught_exception() {
+    Python::with_gil(|py| {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let sys = py.import("sys").unwrap();
+        // stands in for a hook installed by another library
+        py_run!(py, sys, "sys.excepthook = lambda *args: None");
+        let original = sys.getattr("excepthook").unwrap();
+
+        let recorded = seen.clone();
+        let previous = py
+            .set_excepthook(move |ty, value, traceback| {
+                recorded.lock().unwrap().push((
+                    ty.name()?.to_string(),
+                    value.to_string(),
+                    traceback.is_some(),
+                ));
+                Ok(())
+            })
+            .unwrap();
+
+        let err = py
+            .run(c_str!("raise ValueError('uncaught')"), None, None)
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+        err.print(py);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [("ValueError".to_string(), "uncaught".to_string(), true)]
+        );
+
+        // errors from the hook itself are reported, not raised
+        let replaced = py
+            .set_excepthook(|_, _, _| Err(PyRuntimeError::new_err("hook failed")))
+            .unwrap();
+        py_run!(py, sys, "sys.excepthook(ValueError, ValueError('x'), None)");
+
+        // resetting restores each hook that was replaced, not `sys.__excepthook__`
+        py.reset_excepthook(&replaced).unwrap();
+        assert!(sys.getattr("excepthook").unwrap().is(&replaced));
+        py.reset_excepthook(&previous).unwrap();
+        assert!(sys.getattr("excepthook").unwrap().is(&original));
+        assert!(previous.is(&original));
+
+        py_run!(py, sys, "sys.excepthook = sys.__excepthook__");
+    });
+}
@@ -885,3 +885,59 @@ fn test_with_default_item_enum() {
         assert_eq!(result, expected);
     });
 }
+
+#[derive(Debug, PartialEq, FromPyObject)]
+#[pyo3(int_enum)]
+enum Status {
+    Ok = 200,
+    NotFound = 404,
+}
+
+#[derive(Debug, PartialEq, FromPyObject)]
+#[pyo3(int_enum)]
+#[repr(u8)]
+enum Small {
+    A = 1,
+    B = 2,
+}
+
+#[test]
+fn test_int_enum() {
+    Python::with_gil(|py| {
+        assert_eq!(
+            200i32
+                .into_pyobject(py)
+                .unwrap()
+                .extract::<Status>()
+                .unwrap(),
+            Status::Ok
+        );
+        assert_eq!(
+            404i32
+                .into_pyobject(py)
+                .unwrap()
+                .extract::<Status>()
+                .unwrap(),
+            Status::NotFound
+        );
+        assert_eq!(
+            2i32.into_pyobject(py).unwrap().extract::<Small>().unwrap(),
+            Small::B
+        );
+
+        let err = 500i32
+            .into_pyobject(py)
+            .unwrap()
+            .extract::<Status>()
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+        assert_eq!(err.to_string(), "ValueError: 500 is not a valid Status");
+
+        let err = "200"
+            .into_pyobject(py)
+            .unwrap()
+            .extract::<Status>()
+            .unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+    });
+}
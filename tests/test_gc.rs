@@ -195,6 +195,73 @@ fn test_cycle_clear() {
     check.assert_drops_with_gc(ptr);
 }
 
+#[pyclass(gc)]
+struct DerivedGc {
+    #[pyo3(traverse)]
+    cycle: Option<PyObject>,
+    #[pyo3(traverse)]
+    callbacks: Vec<PyObject>,
+    #[pyo3(traverse)]
+    nested: Option<Vec<Py<DerivedGc>>>,
+    _guard: DropGuard,
+}
+
+#[test]
+fn test_derived_gc_cycle_clear() {
+    let (guard, check) = drop_check();
+
+    let ptr = Python::with_gil(|py| {
+        let inst = Bound::new(
+            py,
+            DerivedGc {
+                cycle: None,
+                callbacks: Vec::new(),
+                nested: None,
+                _guard: guard,
+            },
+        )
+        .unwrap();
+
+        // reference cycles through every kind of traversed field
+        inst.borrow_mut().cycle = Some(inst.clone().into_any().unbind());
+        let method = inst.getattr("__class__").unwrap();
+        inst.borrow_mut().callbacks.push(method.unbind());
+        inst.borrow_mut()
+            .callbacks
+            .push(inst.clone().into_any().unbind());
+        inst.borrow_mut().nested = Some(vec![inst.clone().unbind()]);
+
+        #[cfg(not(Py_GIL_DISABLED))]
+        py_run!(py, inst, "import gc; assert gc.is_tracked(inst)");
+        check.assert_not_dropped();
+        inst.as_ptr()
+    });
+
+    check.assert_drops_with_gc(ptr);
+}
+
+#[test]
+#[cfg(not(Py_GIL_DISABLED))]
+fn test_derived_gc_referents() {
+    Python::with_gil(|py| {
+        let inst = Bound::new(
+            py,
+            DerivedGc {
+                cycle: Some(py.None()),
+                callbacks: vec![1i32.into_pyobject(py).unwrap().into_any().unbind()],
+                nested: Some(Vec::new()),
+                _guard: drop_check().0,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            inst,
+            "import gc; assert [r for r in gc.get_referents(inst) if not isinstance(r, type)] == [None, 1]"
+        );
+    });
+}
+
 /// Test that traversing `None` of `Option<Py<T>>` does not cause a segfault
 #[test]
 fn gc_null_traversal() {
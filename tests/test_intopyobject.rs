@@ -252,3 +252,35 @@ fn test_into_py_with() {
         );
     });
 }
+
+#[derive(Debug, IntoPyObject, IntoPyObjectRef)]
+#[pyo3(int_enum)]
+enum Status {
+    Ok = 200,
+    NotFound = 404,
+    Teapot = 418,
+}
+
+#[derive(Debug, IntoPyObject)]
+#[pyo3(int_enum)]
+#[repr(u64)]
+enum Large {
+    Max = u64::MAX,
+}
+
+#[test]
+fn test_int_enum() {
+    Python::with_gil(|py| {
+        let ok = Status::Ok.into_pyobject(py).unwrap();
+        assert_eq!(ok.extract::<i32>().unwrap(), 200);
+
+        let not_found = (&Status::NotFound).into_pyobject(py).unwrap();
+        assert_eq!(not_found.extract::<i32>().unwrap(), 404);
+
+        let teapot = Status::Teapot.into_pyobject(py).unwrap();
+        py_run!(py, teapot, "assert type(teapot) is int and teapot == 418");
+
+        let large = Large::Max.into_pyobject(py).unwrap();
+        assert_eq!(large.extract::<u64>().unwrap(), u64::MAX);
+    });
+}
@@ -2,6 +2,8 @@
 
 use pyo3::prelude::*;
 use pyo3::py_run;
+#[cfg(Py_3_9)]
+use pyo3::types::PyGenericAlias;
 use pyo3::types::PySequence;
 use pyo3::types::{IntoPyDict, PyDict, PyList, PySet, PyString, PyTuple, PyType};
 use pyo3::BoundObject;
@@ -127,6 +129,88 @@ fn class_method_with_args() {
     });
 }
 
+#[cfg(Py_3_9)]
+#[pyclass]
+struct ClassGetItem {}
+
+#[cfg(Py_3_9)]
+#[pymethods]
+impl ClassGetItem {
+    #[classmethod]
+    fn __class_getitem__<'py>(
+        cls: &Bound<'py, PyType>,
+        item: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyGenericAlias>> {
+        PyGenericAlias::new(cls.py(), cls.as_any(), item)
+    }
+}
+
+#[test]
+#[cfg(Py_3_9)]
+fn class_getitem() {
+    Python::with_gil(|py| {
+        let d = [("C", py.get_type::<ClassGetItem>())]
+            .into_py_dict(py)
+            .unwrap();
+        py_assert!(py, *d, "C[int].__origin__ is C");
+        py_assert!(py, *d, "C[int].__args__ == (int,)");
+        py_assert!(py, *d, "C[int, str].__args__ == (int, str)");
+        py_assert!(py, *d, "repr(C[int]) == 'ClassGetItem[int]'");
+    });
+}
+
+#[pyclass]
+struct MethodAliases {
+    items: Vec<i32>,
+}
+
+#[pymethods]
+impl MethodAliases {
+    #[new]
+    fn new() -> Self {
+        MethodAliases {
+            items: vec![10, 20, 30],
+        }
+    }
+
+    /// Returns the item at `index`.
+    #[pyo3(alias = "__getitem__", alias = "item_at")]
+    fn get(&self, index: usize) -> PyResult<i32> {
+        self.items
+            .get(index)
+            .copied()
+            .ok_or_else(|| pyo3::exceptions::PyIndexError::new_err("index out of range"))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "create", alias = "make")]
+    fn build() -> i32 {
+        42
+    }
+}
+
+#[test]
+fn method_aliases() {
+    Python::with_gil(|py| {
+        let obj = Py::new(py, MethodAliases::new()).unwrap();
+        py_assert!(py, obj, "obj.get(1) == 20");
+        py_assert!(py, obj, "obj.item_at(1) == 20");
+        py_assert!(py, obj, "obj[1] == 20");
+        py_expect_exception!(py, obj, "obj[5]", PyIndexError);
+
+        py_assert!(py, obj, "obj.get.__doc__ == obj.item_at.__doc__");
+        py_assert!(py, obj, "obj.get.__text_signature__ == '($self, index)'");
+        py_assert!(
+            py,
+            obj,
+            "obj.item_at.__text_signature__ == obj.get.__text_signature__"
+        );
+
+        py_assert!(py, obj, "type(obj).create() == type(obj).make() == 42");
+        py_assert!(py, obj, "not hasattr(obj, 'build')");
+    });
+}
+
 #[pyclass]
 struct StaticMethod {}
 
@@ -262,6 +262,73 @@ fn test_module_dunder_all() {
     });
 }
 
+#[test]
+fn test_module_add_instance() {
+    #[pyclass(module = "instances")]
+    struct Counter {
+        #[pyo3(get)]
+        count: usize,
+    }
+
+    #[pymethods]
+    impl Counter {
+        fn increment(&mut self) -> usize {
+            self.count += 1;
+            self.count
+        }
+    }
+
+    #[pymodule]
+    fn instances(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        m.add_instance("counter", Counter { count: 0 })?;
+        m.add_instance("other", Counter { count: 10 })?;
+        // re-adding under the same name replaces the instance
+        m.add_instance("other", Counter { count: 20 })?;
+        Ok(())
+    }
+
+    Python::with_gil(|py| {
+        let module = pyo3::wrap_pymodule!(instances)(py);
+        py_run!(
+            py,
+            module,
+            r#"
+assert module.counter.increment() == 1
+assert module.counter.increment() == 2
+assert module.counter.count == 2
+assert module.other.count == 20
+assert type(module.counter).__module__ == "instances"
+assert module.__all__ == ["counter", "other"]
+"#
+        );
+    });
+}
+
+#[test]
+fn test_module_add_class_with_name() {
+    #[pymodule]
+    fn aliases(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        m.add_class::<ValueClass>()?;
+        m.add_class_with_name::<ValueClass>("Value")?;
+        m.add_class_with_name::<ValueClass>("LegacyValue")?;
+        Ok(())
+    }
+
+    Python::with_gil(|py| {
+        let module = pyo3::wrap_pymodule!(aliases)(py);
+        py_run!(
+            py,
+            module,
+            r#"
+assert module.Value is module.ValueClass
+assert module.LegacyValue is module.ValueClass
+assert module.Value.__name__ == "ValueClass"
+assert module.__all__ == ["ValueClass", "Value", "LegacyValue"]
+"#
+        );
+    });
+}
+
 #[pyfunction]
 fn subfunction() -> String {
     "Subfunction".to_string()
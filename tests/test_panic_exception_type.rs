@@ -0,0 +1,71 @@
+#![cfg(feature = "macros")]
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyTypeError};
+use pyo3::panic::{set_panic_exception_type, PanicException};
+use pyo3::prelude::*;
+use pyo3::py_run;
+
+// The panic exception type is process-wide state, so this test runs separately to avoid
+// changing the exceptions seen by other tests.
+
+create_exception!(test_module, RustPanic, PyException);
+
+#[pyfunction]
+fn panics() {
+    panic!("oh no");
+}
+
+#[test]
+fn custom_panic_exception_type() {
+    Python::with_gil(|py| {
+        let panics = wrap_pyfunction!(panics, py).unwrap();
+        let rust_panic = py.get_type::<RustPanic>();
+        let panic_exception = py.get_type::<PanicException>();
+
+        py_run!(
+            py,
+            panics panic_exception,
+            r#"
+try:
+    panics()
+except panic_exception as e:
+    assert str(e) == "oh no"
+else:
+    assert False
+"#
+        );
+
+        set_panic_exception_type(py, &rust_panic).unwrap();
+        py_run!(
+            py,
+            panics rust_panic,
+            r#"
+try:
+    panics()
+except rust_panic as e:
+    assert str(e) == "oh no"
+else:
+    assert False
+"#
+        );
+
+        // only exception types are accepted
+        let err = set_panic_exception_type(py, &py.get_type::<PyAny>()).unwrap_err();
+        assert!(err.is_instance_of::<PyTypeError>(py));
+
+        set_panic_exception_type(py, &panic_exception).unwrap();
+        py_run!(
+            py,
+            panics panic_exception,
+            r#"
+try:
+    panics()
+except panic_exception:
+    pass
+else:
+    assert False
+"#
+        );
+    });
+}
@@ -162,6 +162,43 @@ fn test_bool() {
     })
 }
 
+#[pyclass]
+struct Temperature {
+    celsius: f64,
+}
+
+#[pymethods]
+impl Temperature {
+    fn __str__(&self) -> String {
+        format!("{}°C", self.celsius)
+    }
+
+    fn __format__(&self, spec: &str) -> PyResult<String> {
+        match spec {
+            // no automatic fallback to `__str__` takes place
+            "" => Ok(self.__str__()),
+            "C" => Ok(format!("{:.1}°C", self.celsius)),
+            "F" => Ok(format!("{:.1}°F", self.celsius * 9.0 / 5.0 + 32.0)),
+            _ => Err(PyValueError::new_err(format!(
+                "unknown format code '{}' for Temperature",
+                spec
+            ))),
+        }
+    }
+}
+
+#[test]
+fn test_format() {
+    Python::with_gil(|py| {
+        let inst = Py::new(py, Temperature { celsius: 21.5 }).unwrap();
+        py_assert!(py, inst, "f'{inst}' == '21.5°C'");
+        py_assert!(py, inst, "f'{inst:C}' == '21.5°C'");
+        py_assert!(py, inst, "f'{inst:F}' == '70.7°F'");
+        py_assert!(py, inst, "format(inst, 'F') == '70.7°F'");
+        py_expect_exception!(py, inst, "f'{inst:K}'", PyValueError);
+    })
+}
+
 #[pyclass]
 pub struct LenOverflow;
 
@@ -374,6 +411,10 @@ impl Iterator {
     fn __next__(slf: PyRefMut<'_, Self>) -> Option<i32> {
         slf.iter.lock().unwrap().next()
     }
+
+    fn __length_hint__(&self) -> usize {
+        self.iter.lock().unwrap().size_hint().0
+    }
 }
 
 #[test]
@@ -387,7 +428,11 @@ fn iterator() {
         )
         .unwrap();
         py_assert!(py, inst, "iter(inst) is inst");
-        py_assert!(py, inst, "list(inst) == [5, 6, 7]");
+        py_assert!(py, inst, "__import__('operator').length_hint(inst) == 3");
+        py_assert!(py, inst, "next(inst) == 5");
+        py_assert!(py, inst, "__import__('operator').length_hint(inst) == 2");
+        py_assert!(py, inst, "list(inst) == [6, 7]");
+        py_assert!(py, inst, "__import__('operator').length_hint(inst) == 0");
     });
 }
 
@@ -222,6 +222,58 @@ fn test_from_py_with_defaults() {
     });
 }
 
+#[test]
+fn test_from_py_with_varargs() {
+    fn len(obj: &Bound<'_, PyAny>) -> PyResult<usize> {
+        obj.len()
+    }
+
+    #[pyfunction(signature = (*args, **kwargs))]
+    fn lengths(
+        #[pyo3(from_py_with = len)] args: Vec<usize>,
+        #[pyo3(from_py_with = len)] kwargs: Option<HashMap<String, usize>>,
+    ) -> (Vec<usize>, Option<HashMap<String, usize>>) {
+        (args, kwargs)
+    }
+
+    #[pyfunction(signature = (first, *args, **kwargs))]
+    fn lengths_after_first(
+        first: usize,
+        #[pyo3(from_py_with = len)] args: Vec<usize>,
+        #[pyo3(from_py_with = len)] kwargs: Option<HashMap<String, usize>>,
+    ) -> (usize, Vec<usize>, Option<HashMap<String, usize>>) {
+        (first, args, kwargs)
+    }
+
+    Python::with_gil(|py| {
+        let lengths = wrap_pyfunction!(lengths)(py).unwrap();
+        let lengths_after_first = wrap_pyfunction!(lengths_after_first)(py).unwrap();
+        pyo3::py_run!(
+            py,
+            lengths lengths_after_first,
+            r#"
+assert lengths() == ([], None)
+assert lengths("a", [1, 2], x="abc") == ([1, 2], {"x": 3})
+assert lengths_after_first(7, "ab", y=()) == (7, [2], {"y": 0})
+
+try:
+    lengths("a", 1)
+except TypeError as e:
+    assert str(e).startswith("argument 'args':"), str(e)
+else:
+    assert False
+
+try:
+    lengths_after_first(7, x=1)
+except TypeError as e:
+    assert str(e).startswith("argument 'kwargs':"), str(e)
+else:
+    assert False
+"#
+        );
+    });
+}
+
 #[pyclass]
 #[derive(Debug, FromPyObject)]
 struct ValueClass {
@@ -564,6 +616,127 @@ fn test_some_wrap_arguments() {
     })
 }
 
+#[test]
+fn test_default_referencing_earlier_arguments() {
+    #[pyfunction(signature = (start, stop = start + 10, step = (stop - start) / 5, label = None))]
+    fn range_args(start: i32, stop: i32, step: i32, label: Option<&str>) -> String {
+        format!("{} {} {} {}", start, stop, step, label.unwrap_or("-"))
+    }
+
+    #[pyclass]
+    struct Window {
+        #[pyo3(get)]
+        start: usize,
+        #[pyo3(get)]
+        end: usize,
+    }
+
+    #[pymethods]
+    impl Window {
+        #[new]
+        #[pyo3(signature = (start, end = start + 1))]
+        fn new(start: usize, end: usize) -> Self {
+            Window { start, end }
+        }
+
+        #[pyo3(signature = (offset, length = offset * 2))]
+        fn shifted(&self, offset: usize, length: usize) -> (usize, usize) {
+            (self.start + offset, length)
+        }
+    }
+
+    Python::with_gil(|py| {
+        let function = wrap_pyfunction!(range_args, py).unwrap();
+        py_assert!(py, function, "function(0) == '0 10 2 -'");
+        py_assert!(py, function, "function(5, 30) == '5 30 5 -'");
+        py_assert!(py, function, "function(1, step=3) == '1 11 3 -'");
+        py_assert!(py, function, "function(1, label='x') == '1 11 2 x'");
+        py_expect_exception!(py, function, "function(stop=1)", PyTypeError);
+
+        let window = py.get_type::<Window>();
+        py_assert!(py, window, "window(3).end == 4");
+        py_assert!(py, window, "window(3, 7).end == 7");
+        py_assert!(py, window, "window(3, 7).shifted(1) == (4, 2)");
+        py_assert!(py, window, "window(3, 7).shifted(1, 5) == (4, 5)");
+    })
+}
+
+#[test]
+fn test_result_option_return() {
+    #[pyfunction]
+    fn maybe_half(x: i32) -> PyResult<Option<i32>> {
+        if x < 0 {
+            Err(pyo3::exceptions::PyValueError::new_err("negative"))
+        } else if x % 2 == 1 {
+            Ok(None)
+        } else {
+            Ok(Some(x / 2))
+        }
+    }
+
+    Python::with_gil(|py| {
+        let function = wrap_pyfunction!(maybe_half, py).unwrap();
+        py_assert!(py, function, "function(4) == 2");
+        py_assert!(py, function, "function(3) is None");
+        py_expect_exception!(py, function, "function(-1)", PyValueError);
+    })
+}
+
+#[test]
+fn test_default_referencing_earlier_arguments_with_wrapper_names() {
+    // `function` and `output` are also names of locals in the generated wrapper
+    #[pyfunction(signature = (function, stop = function + 1))]
+    fn uses_function(function: i32, stop: i32) -> (i32, i32) {
+        (function, stop)
+    }
+
+    #[pyfunction(signature = (output, stop = output + 1, total = (0..output).sum()))]
+    fn uses_output(output: i32, stop: i32, total: i32) -> (i32, i32, i32) {
+        (output, stop, total)
+    }
+
+    Python::with_gil(|py| {
+        let f = wrap_pyfunction!(uses_function, py).unwrap();
+        py_assert!(py, f, "f(1) == (1, 2)");
+        py_assert!(py, f, "f(1, 5) == (1, 5)");
+
+        let f = wrap_pyfunction!(uses_output, py).unwrap();
+        py_assert!(py, f, "f(3) == (3, 4, 3)");
+        py_assert!(py, f, "f(3, stop=0) == (3, 0, 3)");
+    })
+}
+
+#[test]
+fn test_default_referencing_earlier_arguments_in_struct_literals() {
+    struct Bounds {
+        start: i32,
+        len: i32,
+    }
+
+    impl Bounds {
+        fn end(&self) -> i32 {
+            self.start + self.len
+        }
+    }
+
+    // the field names `start` and `len` must not be renamed along with the arguments
+    #[pyfunction(signature = (
+        start,
+        len = Bounds { start: 0, len: start }.len * 2,
+        end = Bounds { start, len }.end(),
+        shifted = Some(1).map(|offset: i32| offset + start).unwrap(),
+    ))]
+    fn bounds(start: i32, len: i32, end: i32, shifted: i32) -> (i32, i32, i32, i32) {
+        (start, len, end, shifted)
+    }
+
+    Python::with_gil(|py| {
+        let f = wrap_pyfunction!(bounds, py).unwrap();
+        py_assert!(py, f, "f(3) == (3, 6, 9, 4)");
+        py_assert!(py, f, "f(3, 1) == (3, 1, 4, 4)");
+    })
+}
+
 #[test]
 fn test_reference_to_bound_arguments() {
     #[pyfunction]
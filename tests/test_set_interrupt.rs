@@ -0,0 +1,25 @@
+use pyo3::exceptions::PyKeyboardInterrupt;
+use pyo3::ffi::c_str;
+use pyo3::prelude::*;
+
+// Python only handles signals on the main thread, so this test runs without the default test
+// harness, which would run it on a separate thread.
+
+fn main() {
+    Python::with_gil(|py| {
+        // PyO3 does not install Python's signal handlers when initializing the interpreter
+        py.run(
+            c_str!("import signal; signal.signal(signal.SIGINT, signal.default_int_handler)"),
+            None,
+            None,
+        )
+        .unwrap();
+
+        PyErr::set_interrupt(py);
+        let err = py.check_signals().unwrap_err();
+        assert!(err.is_instance_of::<PyKeyboardInterrupt>(py));
+
+        // the interrupt is only raised once
+        py.check_signals().unwrap();
+    });
+}
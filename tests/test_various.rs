@@ -170,6 +170,72 @@ fn test_pickle() {
     });
 }
 
+#[test]
+fn test_pickle_with_reduce_helper() {
+    use pyo3::pyclass::PyReduce;
+    use pyo3::types::PyDict;
+
+    #[pyclass(module = "test_reduce_module")]
+    struct Record {
+        #[pyo3(get)]
+        name: String,
+        #[pyo3(get)]
+        tags: Vec<String>,
+    }
+
+    #[pymethods]
+    impl Record {
+        #[new]
+        fn new(name: String) -> Self {
+            Record {
+                name,
+                tags: Vec::new(),
+            }
+        }
+
+        fn __reduce__(slf: PyRef<'_, Self>) -> PyResult<PyReduce<'_>> {
+            PyReduce::from_class::<Self, _>(slf.py(), (&slf.name,))?.with_state(&slf.tags)
+        }
+
+        fn __setstate__(&mut self, tags: Vec<String>) {
+            self.tags = tags;
+        }
+    }
+
+    Python::with_gil(|py| {
+        let module = PyModule::new(py, "test_reduce_module").unwrap();
+        module.add_class::<Record>().unwrap();
+        py.import("sys")
+            .unwrap()
+            .getattr("modules")
+            .unwrap()
+            .downcast_into::<PyDict>()
+            .unwrap()
+            .set_item("test_reduce_module", module)
+            .unwrap();
+
+        let record = Py::new(
+            py,
+            Record {
+                name: "rec".to_owned(),
+                tags: vec!["a".to_owned(), "b".to_owned()],
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            record,
+            r#"
+        import pickle
+        other = pickle.loads(pickle.dumps(record))
+        assert type(other) is type(record)
+        assert other.name == "rec"
+        assert other.tags == ["a", "b"]
+    "#
+        );
+    });
+}
+
 /// Testing https://github.com/PyO3/pyo3/issues/1106. A result type that
 /// implements `From<MyError> for PyErr` should be automatically converted
 /// when using `#[pyfunction]`.
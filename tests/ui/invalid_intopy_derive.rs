@@ -138,4 +138,15 @@ enum EnumStructIntoPyWith {
     },
 }
 
+#[derive(IntoPyObject)]
+#[pyo3(int_enum)]
+struct IntEnumStruct {}
+
+#[derive(IntoPyObject)]
+#[pyo3(int_enum)]
+enum IntEnumWithFields {
+    A,
+    B(usize),
+}
+
 fn main() {}
@@ -181,4 +181,46 @@ impl Display for MyEnumInvalidStrFmt {
     }
 }
 
+#[pyclass(leak_on_foreign_drop)]
+struct LeakOnForeignDropWithoutUnsendable {}
+
+#[pyclass]
+struct TraverseWithoutGc {
+    #[pyo3(traverse)]
+    field: Option<PyObject>,
+}
+
+#[pyclass(gc)]
+struct GcBarePy {
+    #[pyo3(traverse)]
+    field: PyObject,
+}
+
+#[pyclass(gc, frozen)]
+struct GcFrozen {
+    #[pyo3(traverse)]
+    field: Option<PyObject>,
+}
+
+#[pyclass(gc)]
+enum GcEnum {
+    A,
+}
+
+#[pyclass(match_args(missing))]
+struct MatchArgsMissingField {
+    #[pyo3(get)]
+    field: i32,
+}
+
+#[pyclass(match_args(field))]
+struct MatchArgsWithoutGetter {
+    field: i32,
+}
+
+#[pyclass(match_args(field))]
+enum MatchArgsEnum {
+    A,
+}
+
 fn main() {}
@@ -50,6 +50,16 @@ fn signature_contains_py(py: Python<'_>) {
     let _ = py;
 }
 
+#[pyfunction(signature = (start, stop = { let start = 1; start + 1 }))]
+fn default_rebinds_earlier_argument(start: i32, stop: i32) {
+    let _ = (start, stop);
+}
+
+#[pyfunction(signature = (start, stop = vec![start; 2].len()))]
+fn default_uses_earlier_argument_in_macro(start: usize, stop: usize) {
+    let _ = (start, stop);
+}
+
 #[pyclass]
 struct MyClass;
 
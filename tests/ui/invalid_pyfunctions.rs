@@ -36,4 +36,8 @@ fn first_argument_not_module<'a, 'py>(
     module.name()
 }
 
+#[pyfunction]
+#[pyo3(alias = "other_name")]
+fn function_with_alias() {}
+
 fn main() {}
@@ -188,6 +188,15 @@ impl MyClass {
     fn method_self_by_value(self) {}
 }
 
+#[pymethods]
+impl MyClass {
+    #[getter]
+    #[pyo3(alias = "other_name")]
+    fn getter_with_alias(&self) -> i32 {
+        0
+    }
+}
+
 macro_rules! macro_invocation {
     () => {};
 }